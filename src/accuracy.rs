@@ -0,0 +1,24 @@
+use sys::*;
+
+/// Accuracy of the `CARDINALITY` and `PAGES` columns reported by `Statement::statistics`.
+///
+/// See [SQLStatistics Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlstatistics-function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Accuracy {
+    /// The values are only returned if they are readily available from the driver or data
+    /// source.
+    Quick,
+    /// The driver unconditionally retrieves the statistics.
+    Ensure,
+}
+
+impl Accuracy {
+    /// Value passed to the driver as `Reserved` argument of `SQLStatistics`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        match *self {
+            Accuracy::Quick => 0,
+            Accuracy::Ensure => 1,
+        }
+    }
+}
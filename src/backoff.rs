@@ -0,0 +1,29 @@
+use std::cmp::{max, min};
+use std::time::Duration;
+
+/// Controls how often a future polls the driver, while a function is still executing
+/// asynchronously. The delay between two polls starts at `initial` and doubles after each poll,
+/// up to `max`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Backoff {
+    /// Delay before the first poll
+    pub initial: Duration,
+    /// Upper bound for the delay between two polls
+    pub max: Duration,
+}
+
+impl Default for Backoff {
+    fn default() -> Backoff {
+        Backoff {
+            initial: Duration::from_millis(1),
+            max: Duration::from_millis(100),
+        }
+    }
+}
+
+impl Backoff {
+    /// Delay after `delay`
+    pub(crate) fn next(&self, delay: Duration) -> Duration {
+        max(min(delay * 2, self.max), self.initial)
+    }
+}
@@ -0,0 +1,25 @@
+use ffi::SQL_BIND_BY_COLUMN;
+use sys::*;
+
+/// Layout of the buffers bound to a block cursor.
+///
+/// See [Binding Columns for Use with Block Cursors][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/binding-columns-for-use-with-block-cursors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindType {
+    /// Each column is bound to an array holding one element per row.
+    Column,
+    /// Each row is bound to an element of an array of structures. Holds the size of a single row
+    /// in bytes. (E.g. `std::mem::size_of::<MyRow>()`)
+    Row(usize),
+}
+
+impl BindType {
+    /// Value passed to the driver for `SQL_ATTR_ROW_BIND_TYPE`
+    pub(crate) fn value(&self) -> SQLULEN {
+        match *self {
+            BindType::Column => SQL_BIND_BY_COLUMN,
+            BindType::Row(size) => size as SQLULEN,
+        }
+    }
+}
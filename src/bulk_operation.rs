@@ -0,0 +1,29 @@
+use sys::*;
+
+/// Operation performed by `Statement::bulk_operations` on the rows held by the bound buffers.
+///
+/// See [SQLBulkOperations Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlbulkoperations-function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkOperation {
+    /// Inserts the values of the bound buffers as new rows into the data source.
+    Add,
+    /// Updates the rows identified by the bound bookmarks with the values of the bound buffers.
+    UpdateByBookmark,
+    /// Deletes the rows identified by the bound bookmarks from the data source.
+    DeleteByBookmark,
+    /// Fetches the rows identified by the bound bookmarks into the bound buffers.
+    FetchByBookmark,
+}
+
+impl BulkOperation {
+    /// Value passed to the driver as `Operation` argument of `SQLBulkOperations`
+    pub(crate) fn value(&self) -> SqlBulkOperation {
+        match *self {
+            BulkOperation::Add => SQL_ADD,
+            BulkOperation::UpdateByBookmark => SQL_UPDATE_BY_BOOKMARK,
+            BulkOperation::DeleteByBookmark => SQL_DELETE_BY_BOOKMARK,
+            BulkOperation::FetchByBookmark => SQL_FETCH_BY_BOOKMARK,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use super::*;
+use sys::*;
+use std::marker::PhantomData;
+use std::ptr::null_mut;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Cancels the processing of a `Statement` from another thread.
+///
+/// Obtained using `Statement::cancel_handle`, before the statement is executed. Canceling has no
+/// effect if the statement is not executing at the time, or has already been dropped. Canceled
+/// executions fail with a diagnostic record of kind `ErrorKind::Canceled`.
+///
+/// See [SQLCancel Function][1]
+/// See [Canceling Functions][2]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlcancel-function
+/// [2]: https://docs.microsoft.com/sql/odbc/reference/develop-app/canceling-functions
+#[derive(Debug, Clone)]
+pub struct CancelHandle<'con> {
+    /// The cancel handle may not outlive the connection of the statement.
+    parent: PhantomData<&'con ()>,
+    shared: Arc<Mutex<SharedHandle>>,
+}
+
+impl<'con> CancelHandle<'con> {
+    pub(crate) fn new(shared: Arc<Mutex<SharedHandle>>) -> Self {
+        CancelHandle {
+            parent: PhantomData,
+            shared,
+        }
+    }
+
+    /// Cancels the processing of the statement. Returns `Success(false)` if the statement has
+    /// already been dropped.
+    pub fn cancel(&self) -> Return<bool> {
+        let shared = lock(&self.shared);
+        if shared.0.is_null() {
+            return Success(false);
+        }
+        // The statement can not be freed while we are holding the lock
+        let ret: Return<()> = unsafe { SQLCancel(shared.0).into() };
+        ret.map(|()| true)
+    }
+}
+
+/// Statement handle shared between a statement and its cancel handles. Set to null, once the
+/// statement is freed.
+#[derive(Debug)]
+pub(crate) struct SharedHandle(pub(crate) SQLHSTMT);
+
+// `SQLCancel` may be called from any thread
+unsafe impl Send for SharedHandle {}
+
+impl SharedHandle {
+    pub(crate) fn new(handle: SQLHSTMT) -> Arc<Mutex<SharedHandle>> {
+        Arc::new(Mutex::new(SharedHandle(handle)))
+    }
+
+    /// Invalidates all cancel handles
+    pub(crate) fn invalidate(shared: &Mutex<SharedHandle>) {
+        lock(shared).0 = null_mut();
+    }
+}
+
+/// A thread panicking while canceling can not leave the handle in an inconsistent state.
+fn lock<'a>(shared: &'a Mutex<SharedHandle>) -> MutexGuard<'a, SharedHandle> {
+    shared.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
@@ -0,0 +1,122 @@
+use sys::*;
+
+/// Numeric descriptor fields of a result set column, which can be retrieved using
+/// `Statement::numeric_col_attribute`.
+///
+/// See [SQLColAttribute Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlcolattribute-function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumericColAttribute {
+    /// `SQL_TRUE` if the column is auto incrementing.
+    AutoUniqueValue,
+    /// `SQL_TRUE` if the column is treated as case sensitive for collations and comparisons.
+    CaseSensitive,
+    /// Concise SQL data type of the column.
+    ConciseType,
+    /// Number of columns in the result set. The column number is ignored.
+    Count,
+    /// Maximum number of characters required to display data from the column.
+    DisplaySize,
+    /// `SQL_TRUE` if the column has a fixed precision and non-zero scale, e.g. money.
+    FixedPrecScale,
+    /// Maximum or actual character length of a character string or binary data type.
+    Length,
+    /// Whether the column accepts NULL values. One of the values of `Nullable`.
+    Nullable,
+    /// Radix of the precision of numeric types. `2` or `10`.
+    NumPrecRadix,
+    /// Length in bytes of a character string or binary data type.
+    OctetLength,
+    /// Precision of numeric types. Number of digits in the fractional seconds of datetime types.
+    Precision,
+    /// Scale of numeric types.
+    Scale,
+    /// How the column can be used in `WHERE` clauses.
+    Searchable,
+    /// Verbose SQL data type of the column.
+    Type,
+    /// `SQL_NAMED` if the column has a name, `SQL_UNNAMED` otherwise.
+    Unnamed,
+    /// `SQL_TRUE` if the column is unsigned or not numeric.
+    Unsigned,
+    /// Whether the column can be updated. `SQL_ATTR_READONLY`, `SQL_ATTR_WRITE` or
+    /// `SQL_ATTR_READWRITE_UNKNOWN`.
+    Updatable,
+}
+
+/// Character descriptor fields of a result set column, which can be retrieved using
+/// `Statement::string_col_attribute`.
+///
+/// See [SQLColAttribute Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlcolattribute-function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringColAttribute {
+    /// Name of the base column of the result set column.
+    BaseColumnName,
+    /// Name of the base table containing the column.
+    BaseTableName,
+    /// Catalog of the table containing the column.
+    CatalogName,
+    /// Label or title of the column, e.g. as specified with `AS`.
+    Label,
+    /// Characters used to prefix a literal of the type of the column.
+    LiteralPrefix,
+    /// Characters used to terminate a literal of the type of the column.
+    LiteralSuffix,
+    /// Localized name of the data type of the column.
+    LocalTypeName,
+    /// Alias or name of the column.
+    Name,
+    /// Schema of the table containing the column.
+    SchemaName,
+    /// Name of the table containing the column.
+    TableName,
+    /// Data source dependent name of the data type of the column.
+    TypeName,
+}
+
+impl NumericColAttribute {
+    /// Value passed to the driver as `FieldIdentifier` argument of `SQLColAttribute`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        use NumericColAttribute::*;
+        match *self {
+            ConciseType => 2,
+            DisplaySize => 6,
+            Unsigned => 8,
+            FixedPrecScale => 9,
+            Updatable => 10,
+            AutoUniqueValue => 11,
+            CaseSensitive => 12,
+            Searchable => 13,
+            NumPrecRadix => 32,
+            Count => 1001,
+            Type => 1002,
+            Length => 1003,
+            Precision => 1005,
+            Scale => 1006,
+            Nullable => 1008,
+            Unnamed => 1012,
+            OctetLength => 1013,
+        }
+    }
+}
+
+impl StringColAttribute {
+    /// Value passed to the driver as `FieldIdentifier` argument of `SQLColAttribute`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        use StringColAttribute::*;
+        match *self {
+            TypeName => 14,
+            TableName => 15,
+            SchemaName => 16,
+            CatalogName => 17,
+            Label => 18,
+            BaseColumnName => 22,
+            BaseTableName => 23,
+            LiteralPrefix => 27,
+            LiteralSuffix => 28,
+            LocalTypeName => 29,
+            Name => 1011,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use sys::*;
+
+/// Concurrency control used by cursors, when updating rows of the result set.
+///
+/// See [Concurrency Control][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/concurrency-control
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Concurrency {
+    /// The cursor is read only. No updates are allowed. The default.
+    ReadOnly,
+    /// Rows are locked, so they can be updated.
+    Lock,
+    /// Optimistic concurrency control comparing row versions or timestamps.
+    RowVersion,
+    /// Optimistic concurrency control comparing values.
+    Values,
+}
+
+impl Concurrency {
+    /// Value passed to the driver for `SQL_ATTR_CONCURRENCY`
+    pub(crate) fn value(&self) -> SQLULEN {
+        match *self {
+            Concurrency::ReadOnly => 1,
+            Concurrency::Lock => 2,
+            Concurrency::RowVersion => 3,
+            Concurrency::Values => 4,
+        }
+    }
+}
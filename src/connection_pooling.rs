@@ -0,0 +1,54 @@
+use sys::*;
+use ffi::{SQL_CP_OFF, SQL_CP_ONE_PER_DRIVER, SQL_CP_ONE_PER_HENV};
+
+/// Connection pooling performed by the driver manager for the whole process, which can be enabled
+/// using `Environment::set_connection_pooling`. Driver-aware pooling is enabled per environment
+/// using `Environment::set_driver_aware_pooling` instead.
+///
+/// See [Driver Manager Connection Pooling][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/driver-manager-connection-pooling
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionPooling {
+    /// Connection pooling is disabled. The default.
+    Off,
+    /// A single pool is maintained for each driver. Connections in the pool are shared between
+    /// environments.
+    OnePerDriver,
+    /// A single pool is maintained for each environment.
+    OnePerHenv,
+}
+
+impl ConnectionPooling {
+    /// Value passed to the driver manager for `SQL_ATTR_CONNECTION_POOLING`
+    pub(crate) fn value(&self) -> SQLUINTEGER {
+        match *self {
+            ConnectionPooling::Off => SQL_CP_OFF,
+            ConnectionPooling::OnePerDriver => SQL_CP_ONE_PER_DRIVER,
+            ConnectionPooling::OnePerHenv => SQL_CP_ONE_PER_HENV,
+        }
+    }
+}
+
+/// How a connection is chosen from the pool, which can be set using `Environment::set_cp_match`.
+///
+/// See [SQLSetEnvAttr Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlsetenvattr-function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CpMatch {
+    /// Only connections matching the connection string and the connection attributes are reused.
+    /// The default.
+    Strict,
+    /// Connections with a matching connection string are reused, regardless of their connection
+    /// attributes.
+    Relaxed,
+}
+
+impl CpMatch {
+    /// Value passed to the driver manager for `SQL_ATTR_CP_MATCH`
+    pub(crate) fn value(&self) -> SQLUINTEGER {
+        match *self {
+            CpMatch::Strict => 0,
+            CpMatch::Relaxed => 1,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use sys::*;
+
+/// Whether a cursor reflects changes made to the result set by other cursors.
+///
+/// See [Cursor Sensitivity][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/cursor-sensitivity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorSensitivity {
+    /// It is unspecified whether changes are visible. The default.
+    Unspecified,
+    /// Changes made by other cursors are not visible.
+    Insensitive,
+    /// Changes made by other cursors are visible.
+    Sensitive,
+}
+
+impl CursorSensitivity {
+    /// Value passed to the driver for `SQL_ATTR_CURSOR_SENSITIVITY`
+    pub(crate) fn value(&self) -> SQLULEN {
+        match *self {
+            CursorSensitivity::Unspecified => 0,
+            CursorSensitivity::Insensitive => 1,
+            CursorSensitivity::Sensitive => 2,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use sys::*;
+
+/// Type of a scrollable cursor.
+///
+/// See [Scrollable Cursor Types][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/scrollable-cursor-types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorType {
+    /// The membership of the result set is fixed. Changes are only detected by keyset-driven or
+    /// dynamic cursors.
+    Static,
+    /// The membership of the result set is fixed by a set of row keys. Changes to the values of
+    /// rows are detected, inserted rows are not.
+    KeysetDriven,
+    /// Changes to the values and membership of the result set are detected while scrolling.
+    Dynamic,
+}
+
+impl CursorType {
+    /// Value passed to the driver for `SQL_ATTR_CURSOR_TYPE`
+    pub(crate) fn value(&self) -> SQLULEN {
+        match *self {
+            CursorType::KeysetDriven => 1,
+            CursorType::Dynamic => 2,
+            CursorType::Static => 3,
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use super::*;
+use backoff::Backoff;
+use sql_str::{self, OwnedSqlStr};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::thread::sleep;
+use std::time::Duration;
+use timer::wake_after;
+
+/// Completes once an asynchronously executing `connect` is complete. Resolves to the same result
+/// as `DataSource::connect`.
+///
+/// While connecting, the driver is polled again after a delay controlled by `Backoff`. Dropping
+/// the future before it is complete cancels the connection attempt. The drop blocks the calling
+/// thread until the driver has acknowledged the cancellation, sleeping between two polls.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct ConnectFuture<'env> {
+    /// `None` once the future is complete
+    data_source: Option<DataSource<'env, Unconnected<'env>>>,
+    /// Arguments of `SQLConnect` must be passed again on each poll
+    data_source_name: OwnedSqlStr,
+    user: OwnedSqlStr,
+    pwd: OwnedSqlStr,
+    /// `true` once `SQLConnect` has been called
+    started: bool,
+    backoff: Backoff,
+    delay: Duration,
+}
+
+impl<'env> ConnectFuture<'env> {
+    /// Changes how often the driver is polled, while connecting
+    pub fn with_backoff(mut self, backoff: Backoff) -> Self {
+        self.backoff = backoff;
+        self.delay = backoff.initial;
+        self
+    }
+
+    fn poll_connect(&mut self) -> ReturnAsync<()> {
+        self.started = true;
+        self.data_source
+            .as_mut()
+            .expect("Future polled after completion")
+            .handle
+            .connect_async(&self.data_source_name, &self.user, &self.pwd)
+    }
+}
+
+impl<'env> Future for ConnectFuture<'env> {
+    type Output = Return<Connection<'env, AutocommitOn>, DataSource<'env, Unconnected<'env>>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let ret = this.poll_connect();
+        if let ReturnAsync::StillExecuting(()) = ret {
+            wake_after(this.delay, cx.waker().clone());
+            this.delay = this.backoff.next(this.delay);
+            return Poll::Pending;
+        }
+        let mut data_source = this.data_source.take().unwrap();
+        // All other connection functions are meant to block
+        let disabled = data_source.handle.set_async_dbc_functions_enable(false);
+        Poll::Ready(match (ret, disabled) {
+            (ReturnAsync::Success(()), Success(())) => Success(data_source.transit()),
+            (ReturnAsync::Success(()), _) |
+            (ReturnAsync::Info(()), _) => Info(data_source.transit()),
+            _ => Error(data_source),
+        })
+    }
+}
+
+impl<'env> Drop for ConnectFuture<'env> {
+    fn drop(&mut self) {
+        if !self.started || self.data_source.is_none() {
+            return;
+        }
+        // The connection handle can not be freed, while a connection attempt is executing.
+        let _ = self.data_source.as_mut().unwrap().handle.cancel();
+        let mut delay = self.backoff.initial;
+        loop {
+            match self.poll_connect() {
+                ReturnAsync::StillExecuting(()) => {
+                    sleep(delay);
+                    delay = self.backoff.next(delay);
+                }
+                ReturnAsync::Success(()) | ReturnAsync::Info(()) => {
+                    // Too late to cancel. Dropping the connection disconnects it.
+                    let data_source = self.data_source.take().unwrap();
+                    let _: Connection<'env, AutocommitOn> = data_source.transit();
+                    return;
+                }
+                ReturnAsync::NoData(()) | ReturnAsync::Error(()) => return,
+            }
+        }
+    }
+}
+
+impl<'env> DataSource<'env, Unconnected<'env>> {
+    /// Connects to a data source asynchronously, like `connect`. Enables asynchronous execution of
+    /// connection functions and starts connecting, once the returned future is polled. Returns
+    /// `Error` if the driver does not support asynchronous connection functions.
+    ///
+    /// Asynchronous execution of connection functions is disabled again, once connecting is
+    /// complete. Should this fail, the connection is returned with `Info`.
+    ///
+    /// See [Asynchronous Execution (Polling Method)][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/asynchronous-execution-polling-method
+    pub fn connect_future<DSN, U, P>(
+        mut self,
+        data_source_name: &DSN,
+        user: &U,
+        pwd: &P,
+    ) -> Return<ConnectFuture<'env>, Self>
+    where
+        DSN: SqlStr + ?Sized,
+        U: SqlStr + ?Sized,
+        P: SqlStr + ?Sized,
+    {
+        let ret = self.handle.set_async_dbc_functions_enable(true);
+        let backoff = Backoff::default();
+        let future = |data_source| {
+            ConnectFuture {
+                data_source: Some(data_source),
+                data_source_name: sql_str::to_owned(data_source_name),
+                user: sql_str::to_owned(user),
+                pwd: sql_str::to_owned(pwd),
+                started: false,
+                backoff,
+                delay: backoff.initial,
+            }
+        };
+        match ret {
+            Success(()) => Success(future(self)),
+            Info(()) => Info(future(self)),
+            Error(()) => Error(self),
+        }
+    }
+}
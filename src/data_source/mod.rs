@@ -1,13 +1,19 @@
+pub use self::connect_future::ConnectFuture;
 pub use self::connected::{Connected, AutocommitOff, AutocommitOn, AutocommitMode};
 pub use self::hdbc_wrapper::HDbcWrapper;
+pub use self::transaction::Transaction;
 pub use self::unconnected::Unconnected;
 use super::*;
+use ffi::SQL_API_ODBC3_ALL_FUNCTIONS_SIZE;
 use sys::*;
 use std::ops::DerefMut;
+use std::time::Duration;
 
+mod connect_future;
 mod connected;
 mod unconnected;
 mod hdbc_wrapper;
+mod transaction;
 
 /// A `DataSource` is used to query and manipulate a data source.
 ///
@@ -76,6 +82,27 @@ impl<'env> DataSource<'env, Unconnected<'env>> {
         })
     }
 
+    /// Limits the time to wait for a login request to complete, e.g. during `connect`. Fractions
+    /// of a second are rounded up. A timeout of zero waits indefinitely. Logins exceeding the
+    /// timeout fail with a diagnostic record of kind `ErrorKind::Timeout`.
+    ///
+    /// See [Connection Attributes][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlsetconnectattr-function
+    pub fn set_login_timeout(&mut self, timeout: Duration) -> Return<()> {
+        self.handle.set_login_timeout(timeout::seconds(timeout))
+    }
+
+    /// Limits the time to wait for any request on the connection to complete, other than query
+    /// execution and login. Fractions of a second are rounded up. A timeout of zero waits
+    /// indefinitely. Requests exceeding the timeout fail with a diagnostic record of kind
+    /// `ErrorKind::Timeout`.
+    ///
+    /// See [Connection Attributes][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlsetconnectattr-function
+    pub fn set_connection_timeout(&mut self, timeout: Duration) -> Return<()> {
+        self.handle.set_connection_timeout(timeout::seconds(timeout))
+    }
+
     /// Establishes connections to a driver and a data source. The connection handle references
     /// storage of all information about the connection to the data source, including status,
     /// transaction state, and error information.
@@ -166,7 +193,67 @@ impl<'env, AC: AutocommitMode> Connection<'env, AC> {
 
     /// `true` if the data source is set to READ ONLY mode, `false` otherwise.
     pub fn is_read_only(&mut self) -> Return<bool> {
-        self.handle.is_read_only()
+        self.info(YesNoInfo::DataSourceReadOnly)
+    }
+
+    /// Returns information about the driver and data source. The type of the result depends on
+    /// the kind of information requested, e.g. `StringInfo::DbmsName` returns a `String`.
+    ///
+    /// See [SQLGetInfo Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlgetinfo-function
+    pub fn info<I>(&self, info_type: I) -> Return<I::Value>
+    where
+        I: ConnectionInfo,
+    {
+        info_type.get(&self.handle)
+    }
+
+    /// Returns the set of ODBC functions supported by the driver. Use it to choose between
+    /// alternative code paths at runtime, depending on the capabilities of the driver.
+    ///
+    /// See [SQLGetFunctions Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlgetfunctions-function
+    pub fn supported_functions(&self) -> Return<SupportedFunctions> {
+        let mut bits = [0; SQL_API_ODBC3_ALL_FUNCTIONS_SIZE];
+        self.handle
+            .supported_functions(&mut bits)
+            .map(|()| SupportedFunctions::new(bits))
+    }
+
+    /// Sets the transaction isolation level of the connection. Must not be called while a
+    /// transaction is open.
+    ///
+    /// Isolation levels defined by ODBC are checked against the levels supported by the data
+    /// source first. Returns `IsolationLevelError::Unsupported` without changing the isolation
+    /// level, if the level is not supported. Driver specific levels are passed to the driver
+    /// unchecked.
+    ///
+    /// See [Setting the Transaction Isolation Level][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/setting-the-transaction-isolation-level
+    pub fn set_isolation_level(
+        &mut self,
+        level: IsolationLevel,
+    ) -> Return<(), IsolationLevelError> {
+        // `Other` may hold the value of a level defined by ODBC
+        let level = IsolationLevel::from_value(level.value());
+        if level.is_standard() {
+            match self.info(TxnIsolationOption) {
+                Success(supported) | Info(supported) => {
+                    if !supported.contains(level) {
+                        return Error(IsolationLevelError::Unsupported(supported));
+                    }
+                }
+                Error(()) => return Error(IsolationLevelError::Driver),
+            }
+        }
+        self.handle
+            .set_isolation_level(level.value())
+            .map_error(|()| IsolationLevelError::Driver)
+    }
+
+    /// Current transaction isolation level of the connection
+    pub fn isolation_level(&self) -> Return<IsolationLevel> {
+        self.handle.isolation_level().map(IsolationLevel::from_value)
     }
 }
 
@@ -189,6 +276,11 @@ impl<'env> Connection<'env, AutocommitOff> {
     pub fn rollback(&mut self) -> Return<()> {
        self.handle.rollback()
     }
+
+    /// Starts a transaction, which is rolled back unless committed explicitly. See `Transaction`.
+    pub fn transaction(&mut self) -> Transaction<'_, 'env> {
+        Transaction::new(self)
+    }
 }
 
 impl<'env> Connection<'env, AutocommitOn> {
@@ -0,0 +1,85 @@
+use super::*;
+
+/// Guard for a transaction on a `Connection` with autocommit disabled.
+///
+/// The transaction ends with either `commit` or `rollback`. Should the guard be dropped before,
+/// e.g. due to an early return or a panic, the transaction is rolled back. Statements allocated
+/// using `statement` borrow the guard, so they can not outlive the transaction.
+///
+/// See [Transactions][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/transactions-odbc
+#[derive(Debug)]
+#[must_use = "dropping the transaction rolls it back"]
+pub struct Transaction<'c, 'env: 'c> {
+    connection: &'c mut Connection<'env, AutocommitOff>,
+    /// `true` once the transaction has been committed or rolled back
+    finished: bool,
+}
+
+impl<'c, 'env> Transaction<'c, 'env> {
+    /// Starts a transaction on `connection`. ODBC starts the transaction implicitly with the first
+    /// statement executed.
+    pub fn new(connection: &'c mut Connection<'env, AutocommitOff>) -> Self {
+        Transaction {
+            connection,
+            finished: false,
+        }
+    }
+
+    /// Allocates a new `Statement`, executing as part of the transaction
+    pub fn statement(&self) -> Return<Statement<'_, 'static, 'static>> {
+        Statement::with_parent(self.connection)
+    }
+
+    /// Commits the transaction. The guard is returned on error, so the transaction can still be
+    /// rolled back.
+    pub fn commit(mut self) -> Return<(), Self> {
+        match self.connection.commit() {
+            Success(()) => {
+                self.finished = true;
+                Success(())
+            }
+            Info(()) => {
+                self.finished = true;
+                Info(())
+            }
+            Error(()) => Error(self),
+        }
+    }
+
+    /// Rolls back the transaction. The guard is returned on error, so rolling back can be
+    /// retried. Dropping it tries once more.
+    pub fn rollback(mut self) -> Return<(), Self> {
+        match self.connection.rollback() {
+            Success(()) => {
+                self.finished = true;
+                Success(())
+            }
+            Info(()) => {
+                self.finished = true;
+                Info(())
+            }
+            Error(()) => Error(self),
+        }
+    }
+}
+
+impl<'c, 'env> Drop for Transaction<'c, 'env> {
+    fn drop(&mut self) {
+        if !self.finished {
+            // There is no way to report an error from within `drop`. Panicking would abort the
+            // process, if the transaction is dropped due to a panic.
+            let _ = self.connection.rollback();
+        }
+    }
+}
+
+impl<'c, 'env> Diagnostics for Transaction<'c, 'env> {
+    fn diagnostics(
+        &self,
+        rec_number: SQLSMALLINT,
+        message_text: &mut [SQLCHAR],
+    ) -> ReturnOption<DiagResult> {
+        self.connection.diagnostics(rec_number, message_text)
+    }
+}
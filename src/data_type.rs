@@ -21,8 +21,60 @@ pub enum DataType {
     Double,
     /// Variadic sized single byte character data
     Varchar(SQLULEN),
+    /// Variadic sized single byte character data without a practical maximum length
+    LongVarchar(SQLULEN),
+    /// Fixed sized wide character data
+    WChar(SQLULEN),
+    /// Variadic sized wide character data
+    WVarchar(SQLULEN),
+    /// Variadic sized wide character data without a practical maximum length
+    WLongVarchar(SQLULEN),
+    /// Fixed sized binary data
+    Binary(SQLULEN),
+    /// Variadic sized binary data
+    Varbinary(SQLULEN),
+    /// Variadic sized binary data without a practical maximum length
+    LongVarbinary(SQLULEN),
+    /// Integer numerical with precision 19
+    BigInt,
+    /// Integer numerical with precision 3
+    TinyInt,
+    /// Single bit binary data
+    Bit,
+    /// Year, month and day
+    Date,
+    /// Hour, minute and second, with the number of digits of fractional seconds
+    Time(SQLSMALLINT),
+    /// Date and time, with the number of digits of fractional seconds
+    Timestamp(SQLSMALLINT),
 }
 
+/// SQL data types covered by `DataType`
+const SUPPORTED_TYPES: [SqlDataType; 22] = [
+    SQL_CHAR,
+    SQL_NUMERIC,
+    SQL_DECIMAL,
+    SQL_INTEGER,
+    SQL_SMALLINT,
+    SQL_FLOAT,
+    SQL_REAL,
+    SQL_DOUBLE,
+    SQL_VARCHAR,
+    SQL_EXT_LONGVARCHAR,
+    SQL_EXT_WCHAR,
+    SQL_EXT_WVARCHAR,
+    SQL_EXT_WLONGVARCHAR,
+    SQL_EXT_BINARY,
+    SQL_EXT_VARBINARY,
+    SQL_EXT_LONGVARBINARY,
+    SQL_EXT_BIGINT,
+    SQL_EXT_TINYINT,
+    SQL_EXT_BIT,
+    SQL_DATE,
+    SQL_TIME,
+    SQL_TIMESTAMP,
+];
+
 /// Determines the type stored at the data source
 ///
 /// See [Data Types][1]
@@ -46,11 +98,37 @@ impl DataType {
             SQL_REAL => Some(Real),
             SQL_DOUBLE => Some(Double),
             SQL_VARCHAR => Some(Varchar(column_size)),
+            SQL_EXT_LONGVARCHAR => Some(LongVarchar(column_size)),
+            SQL_EXT_WCHAR => Some(WChar(column_size)),
+            SQL_EXT_WVARCHAR => Some(WVarchar(column_size)),
+            SQL_EXT_WLONGVARCHAR => Some(WLongVarchar(column_size)),
+            SQL_EXT_BINARY => Some(Binary(column_size)),
+            SQL_EXT_VARBINARY => Some(Varbinary(column_size)),
+            SQL_EXT_LONGVARBINARY => Some(LongVarbinary(column_size)),
+            SQL_EXT_BIGINT => Some(BigInt),
+            SQL_EXT_TINYINT => Some(TinyInt),
+            SQL_EXT_BIT => Some(Bit),
+            SQL_DATE => Some(Date),
+            SQL_TIME => Some(Time(decimal_digits)),
+            SQL_TIMESTAMP => Some(Timestamp(decimal_digits)),
             SQL_UNKNOWN_TYPE => None,
             other => panic!("Returned unsupported type: {:?}", other),
         }
     }
 
+    /// Same as `new`, but takes the SQL data type as integer as returned by the driver. `None` if
+    /// the type is not covered by `DataType`.
+    pub(crate) fn from_raw(
+        data_type: SQLSMALLINT,
+        column_size: SQLULEN,
+        decimal_digits: SQLSMALLINT,
+    ) -> Option<DataType> {
+        SUPPORTED_TYPES
+            .iter()
+            .find(|&&supported| supported as SQLSMALLINT == data_type)
+            .and_then(|&supported| DataType::new(supported, column_size, decimal_digits))
+    }
+
     /// See [SQL Data Types][1]
     /// [1]: https://docs.microsoft.com/sql/odbc/reference/appendixes/sql-data-types
     pub fn sql_data_type(&self) -> SqlDataType {
@@ -65,6 +143,19 @@ impl DataType {
             Real => SQL_REAL,
             Double => SQL_DOUBLE,
             Varchar(_) => SQL_VARCHAR,
+            LongVarchar(_) => SQL_EXT_LONGVARCHAR,
+            WChar(_) => SQL_EXT_WCHAR,
+            WVarchar(_) => SQL_EXT_WVARCHAR,
+            WLongVarchar(_) => SQL_EXT_WLONGVARCHAR,
+            Binary(_) => SQL_EXT_BINARY,
+            Varbinary(_) => SQL_EXT_VARBINARY,
+            LongVarbinary(_) => SQL_EXT_LONGVARBINARY,
+            BigInt => SQL_EXT_BIGINT,
+            TinyInt => SQL_EXT_TINYINT,
+            Bit => SQL_EXT_BIT,
+            Date => SQL_DATE,
+            Time(_) => SQL_TIME,
+            Timestamp(_) => SQL_TIMESTAMP,
         }
     }
 
@@ -79,7 +170,18 @@ impl DataType {
             SmallInt => 5,
             Float | Double => 15,
             Real => 7,
-            Char(len) | Varchar(len) => len,
+            BigInt => 19,
+            TinyInt => 3,
+            Bit => 1,
+            Date => 10,
+            // `hh:mm:ss`, followed by a point and the fractional seconds
+            Time(0) => 8,
+            Time(precision) => 9 + precision as SQLULEN,
+            // `yyyy-mm-dd hh:mm:ss`, followed by a point and the fractional seconds
+            Timestamp(0) => 19,
+            Timestamp(precision) => 20 + precision as SQLULEN,
+            Char(len) | Varchar(len) | LongVarchar(len) | WChar(len) | WVarchar(len) |
+            WLongVarchar(len) | Binary(len) | Varbinary(len) | LongVarbinary(len) => len,
         }
     }
 
@@ -88,9 +190,13 @@ impl DataType {
     pub fn decimal_digits(&self) -> SQLSMALLINT {
         use DataType::*;
         match *self {
-            Char(_) | Integer | Float | Real | Double | Varchar(_) => 0,
+            Char(_) | Integer | Float | Real | Double | Varchar(_) | LongVarchar(_) | WChar(_) |
+            WVarchar(_) | WLongVarchar(_) | Binary(_) | Varbinary(_) | LongVarbinary(_) | BigInt |
+            TinyInt | Bit | Date => 0,
             Numeric(_, scale) |
             Decimal(_, scale) => scale,
+            Time(precision) |
+            Timestamp(precision) => precision,
             SmallInt => 5,
         }
     }
@@ -0,0 +1,91 @@
+use sys::*;
+
+/// Integer fields of a descriptor, which can be read using `Descriptor::field`.
+///
+/// Header fields apply to the descriptor as a whole and ignore the record number. All other
+/// fields describe a single record, i.e. a parameter or column.
+///
+/// See [SQLSetDescField Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlsetdescfield-function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DescField {
+    /// Header field. `SQL_DESC_ALLOC_AUTO` for implicit, `SQL_DESC_ALLOC_USER` for explicit
+    /// descriptors.
+    AllocType,
+    /// Header field. Number of rows in the row set, or number of values for each parameter.
+    ArraySize,
+    /// Header field. `0` for column wise binding, the size of the row structure otherwise.
+    BindType,
+    /// Header field. Index of the highest numbered record which contains data.
+    Count,
+    /// `SQL_TRUE` if the column is auto incrementing.
+    AutoUniqueValue,
+    /// Concise C or SQL data type of the record.
+    ConciseType,
+    /// Subcode of datetime and interval types.
+    DatetimeIntervalCode,
+    /// Maximum or actual length of a character string or binary data type in characters.
+    Length,
+    /// Whether the column or parameter accepts NULL values. One of the values of `Nullable`.
+    Nullable,
+    /// Length in bytes of a character string or binary data type, or of the bound buffer.
+    OctetLength,
+    /// Input / output type of a parameter.
+    ParameterType,
+    /// Precision of numeric types. Number of digits in the fractional seconds of datetime types.
+    Precision,
+    /// Scale of numeric types.
+    Scale,
+    /// Verbose C or SQL data type of the record.
+    Type,
+    /// `SQL_NAMED` if the column or parameter has a name, `SQL_UNNAMED` otherwise.
+    Unnamed,
+    /// `SQL_TRUE` if the column is unsigned or not numeric.
+    Unsigned,
+}
+
+/// Type of the value of a `DescField`
+pub(crate) enum FieldType {
+    SmallInt,
+    Integer,
+    Len,
+    ULen,
+}
+
+impl DescField {
+    /// Value passed to the driver as `FieldIdentifier` argument of `SQLGetDescField` and
+    /// `SQLSetDescField`
+    pub(crate) fn value(&self) -> SQLSMALLINT {
+        use DescField::*;
+        match *self {
+            ConciseType => 2,
+            Unsigned => 8,
+            AutoUniqueValue => 11,
+            ArraySize => 20,
+            BindType => 25,
+            ParameterType => 33,
+            Count => 1001,
+            Type => 1002,
+            Length => 1003,
+            Precision => 1005,
+            Scale => 1006,
+            DatetimeIntervalCode => 1007,
+            Nullable => 1008,
+            Unnamed => 1012,
+            OctetLength => 1013,
+            AllocType => 1099,
+        }
+    }
+
+    /// The driver expects and returns values of exactly this type.
+    pub(crate) fn field_type(&self) -> FieldType {
+        use DescField::*;
+        match *self {
+            ArraySize | Length => FieldType::ULen,
+            OctetLength => FieldType::Len,
+            AutoUniqueValue | BindType => FieldType::Integer,
+            AllocType | ConciseType | Count | DatetimeIntervalCode | Nullable | ParameterType |
+            Precision | Scale | Type | Unnamed | Unsigned => FieldType::SmallInt,
+        }
+    }
+}
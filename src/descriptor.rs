@@ -0,0 +1,185 @@
+use super::*;
+use desc_field::FieldType;
+use sys::*;
+use std::marker::PhantomData;
+
+/// Value of the `SQL_DESC_NAME` field
+const SQL_DESC_NAME: SQLSMALLINT = 1011;
+
+/// A `Descriptor` describes either the buffers bound to the parameters or columns of a statement
+/// (application descriptors), or the parameters and columns themselves (implementation
+/// descriptors). Each record of a descriptor describes one parameter or column.
+///
+/// Every statement owns four implicitly allocated descriptors, which can be accessed using e.g.
+/// `Statement::app_row_desc`. Explicit descriptors are allocated by the application using
+/// `with_parent`. They can be used as application descriptors of one or more statements.
+///
+/// A `Descriptor` may not outlive the buffers referenced by its records, which is tracked by the
+/// `'buf` lifetime.
+///
+/// See [Descriptor Handles][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/descriptor-handles
+#[derive(Debug)]
+pub struct Descriptor<'con, 'buf, K = Explicit> {
+    kind: PhantomData<K>,
+    /// Descriptor may not outlive buffers bound to its records.
+    buffers: PhantomData<&'buf [u8]>,
+    handle: HDesc<'con>,
+}
+
+/// Kind of `Descriptor`. Allocated by the application and freed when dropped.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)]
+pub enum Explicit {}
+/// Kind of `Descriptor`. Allocated implicitly along with a statement and borrowed from it.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)]
+pub enum Implicit {}
+
+impl<'con, 'buf, K> Descriptor<'con, 'buf, K> {
+    /// Provides access to the raw ODBC Descriptor Handle
+    pub fn as_raw(&self) -> SQLHDESC {
+        self.handle.as_raw()
+    }
+
+    /// Reads an integer field of the descriptor. Header fields ignore `rec_number`, records are
+    /// numbered starting at `1`. Record `0` is the bookmark column.
+    ///
+    /// See [SQLGetDescField Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlgetdescfield-function
+    pub fn field(&self, rec_number: SQLSMALLINT, field: DescField) -> Return<SQLLEN> {
+        let handle = &self.handle;
+        let id = field.value();
+        match field.field_type() {
+            FieldType::SmallInt => handle.field::<SQLSMALLINT>(rec_number, id).map(From::from),
+            FieldType::Integer => handle.field::<SQLINTEGER>(rec_number, id).map(|v| v as SQLLEN),
+            FieldType::Len => handle.field::<SQLLEN>(rec_number, id),
+            FieldType::ULen => handle.field::<SQLULEN>(rec_number, id).map(|v| v as SQLLEN),
+        }
+    }
+
+    /// Reads the name of a parameter or column into `name`. Returns the length of the name in
+    /// bytes, which may be larger than `name` if it has been truncated.
+    pub fn name<T>(&self, rec_number: SQLSMALLINT, name: &mut T) -> Return<SQLINTEGER>
+    where
+        T: OutputBuffer + ?Sized,
+    {
+        self.handle.string_field(rec_number, SQL_DESC_NAME, name)
+    }
+
+    /// Sets an integer field of the descriptor.
+    ///
+    /// # Safety
+    /// Fields like `ArraySize`, `Type` or `OctetLength` describe the buffers bound to the records.
+    /// It is the callers responsibility to keep them consistent with these buffers.
+    ///
+    /// See [SQLSetDescField Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlsetdescfield-function
+    pub unsafe fn set_field(
+        &mut self,
+        rec_number: SQLSMALLINT,
+        field: DescField,
+        value: SQLLEN,
+    ) -> Return<()> {
+        self.handle.set_integer_field(rec_number, field.value(), value)
+    }
+
+    /// Express state transition
+    fn transit<'b>(self) -> Descriptor<'con, 'b, K> {
+        Descriptor {
+            kind: PhantomData,
+            buffers: PhantomData,
+            handle: self.handle,
+        }
+    }
+}
+
+impl<'con> Descriptor<'con, 'static, Explicit> {
+    /// Allocates a new explicit `Descriptor`
+    ///
+    /// See [Explicit Descriptor Allocation][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/explicit-descriptor-allocation
+    pub fn with_parent<AC: AutocommitMode>(parent: &'con Connection<AC>) -> Return<Self> {
+        HDesc::allocate(parent.as_hdbc()).map(|handle| {
+            Descriptor {
+                kind: PhantomData,
+                buffers: PhantomData,
+                handle,
+            }
+        })
+    }
+}
+
+impl<'con, 'buf> Descriptor<'con, 'buf, Implicit> {
+    /// Wraps a descriptor implicitly allocated for a statement. Used by `Statement`.
+    pub(crate) unsafe fn from_raw_implicit(raw: SQLHDESC) -> Self {
+        Descriptor {
+            kind: PhantomData,
+            buffers: PhantomData,
+            handle: HDesc::implicit(raw),
+        }
+    }
+}
+
+impl<'con, 'buf> Descriptor<'con, 'buf, Explicit> {
+    /// Binds a buffer and its indicator to a record of the descriptor. Sets the type, length and
+    /// data pointer of the record in a single call. Datetime and interval types are described by
+    /// their verbose type and datetime interval code.
+    ///
+    /// # Result
+    /// This method consumes the descriptor and returns a new one which may not outlive the bound
+    /// buffers.
+    ///
+    /// # Panics
+    /// If `T` has the C data type `SQL_C_NUMERIC`. Its precision and scale can not be derived
+    /// from the type, so numeric records must be described using `set_field`.
+    ///
+    /// See [SQLSetDescRec Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlsetdescrec-function
+    pub fn set_rec<'b, T>(
+        mut self,
+        rec_number: SQLSMALLINT,
+        value: &'b mut T,
+        indicator: &'b mut SQLLEN,
+    ) -> Return<Descriptor<'con, 'b>, Self>
+    where
+        T: CDataType + ?Sized,
+        'buf: 'b,
+    {
+        match unsafe { self.handle.set_rec(rec_number, value, indicator) } {
+            Success(()) => Success(self.transit()),
+            Info(()) => Info(self.transit()),
+            Error(()) => Error(self),
+        }
+    }
+
+    /// Copies all fields of `source` into this descriptor, e.g. to reuse the column bindings of
+    /// one statement for another. Afterwards this descriptor references the buffers bound to
+    /// `source`, and may therefore not outlive them.
+    ///
+    /// See [SQLCopyDesc Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlcopydesc-function
+    pub fn copy_from<'c, 'b, K>(
+        mut self,
+        source: &Descriptor<'c, 'b, K>,
+    ) -> Return<Descriptor<'con, 'b>, Self>
+    where
+        'buf: 'b,
+    {
+        match unsafe { self.handle.copy_from(&source.handle) } {
+            Success(()) => Success(self.transit()),
+            Info(()) => Info(self.transit()),
+            Error(()) => Error(self),
+        }
+    }
+}
+
+impl<'con, 'buf, K> Diagnostics for Descriptor<'con, 'buf, K> {
+    fn diagnostics(
+        &self,
+        rec_number: SQLSMALLINT,
+        message_text: &mut [SQLCHAR],
+    ) -> ReturnOption<DiagResult> {
+        self.handle.diagnostics(rec_number, message_text)
+    }
+}
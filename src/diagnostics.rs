@@ -18,6 +18,30 @@ pub struct DiagResult {
     pub text_length: SQLSMALLINT,
 }
 
+impl DiagResult {
+    /// Classifies the diagnostic record by its SQLSTATE
+    pub fn kind(&self) -> ErrorKind {
+        match &self.state[..5] {
+            b"HY008" => ErrorKind::Canceled,
+            b"HYT00" | b"HYT01" => ErrorKind::Timeout,
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+/// Coarse classification of a diagnostic record, for errors applications commonly want to handle
+/// differently from others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// `HY008` Operation canceled, e.g. using a `CancelHandle`
+    Canceled,
+    /// `HYT00` Timeout expired or `HYT01` Connection timeout expired, e.g. after the duration set
+    /// using `Statement::set_query_timeout` or `DataSource::set_login_timeout`
+    Timeout,
+    /// Any other SQLSTATE
+    Other,
+}
+
 /// A type implementing this trait is able to provide diagnostic information regarding the last
 /// method call.
 pub trait Diagnostics {
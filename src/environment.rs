@@ -1,5 +1,6 @@
 use super::*;
 use sys::*;
+use ffi::{SQL_CP_DRIVER_AWARE, SQL_CP_OFF};
 use std::marker::PhantomData;
 use version::VersionOption;
 
@@ -99,6 +100,36 @@ impl Environment<NoVersion> {
         })
     }
 
+    /// Enables connection pooling by the driver manager for the whole process. Affects only
+    /// environments allocated afterwards, so it must be called before `Environment::new`.
+    ///
+    /// Pooled connections are reused, if a connection with a matching connection string is
+    /// requested, after another one has been disconnected. See `set_cp_match`.
+    ///
+    /// See [Driver Manager Connection Pooling][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/driver-manager-connection-pooling
+    pub fn set_connection_pooling(pooling: ConnectionPooling) -> Return<()> {
+        HEnv::set_process_connection_pooling(pooling.value())
+    }
+
+    /// Enables driver-aware connection pooling for connections allocated from this `Environment`,
+    /// or disables connection pooling for it. Drivers without support for driver-aware pooling
+    /// use one pool per environment instead. Only possible before the version is declared, and
+    /// therefore before any connection is allocated.
+    ///
+    /// See [Driver-Aware Connection Pooling][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/driver-aware-connection-pooling
+    pub fn set_driver_aware_pooling(&mut self, enabled: bool) -> Return<()> {
+        let pooling = if enabled { SQL_CP_DRIVER_AWARE } else { SQL_CP_OFF };
+        self.handle.set_connection_pooling(pooling)
+    }
+
+    /// Sets how strictly connections requested by the application must match pooled connections,
+    /// in order to be reused.
+    pub fn set_cp_match(&mut self, cp_match: CpMatch) -> Return<()> {
+        self.handle.set_cp_match(cp_match.value())
+    }
+
     /// Before an application allocates a connection which specification it follows. Currently
     /// these bindings only support ODBC 3.x.
     ///
@@ -0,0 +1,280 @@
+//! ODBC functions and constants which `odbc-sys` does not provide, or not in a form usable by this
+//! crate.
+//!
+//! `odbc-sys` models statement attributes as an enumeration, which does not cover every attribute
+//! defined in the ODBC headers. Passing a value which is not a variant of a Rust `enum` through
+//! FFI is undefined behaviour, so attributes are declared here as plain integers instead.
+//!
+//! Symbols are resolved against the driver manager linked by `odbc-sys`.
+#![allow(non_snake_case)]
+
+use sys::*;
+
+/// Value of `FunctionId` passed to `SQLGetFunctions`, to query all ODBC 3 functions at once.
+pub const SQL_API_ODBC3_ALL_FUNCTIONS: SQLUSMALLINT = 999;
+/// Number of elements of the bitset filled by `SQLGetFunctions` for `SQL_API_ODBC3_ALL_FUNCTIONS`
+pub const SQL_API_ODBC3_ALL_FUNCTIONS_SIZE: usize = 250;
+
+// Values of the environment attribute `SQL_ATTR_CONNECTION_POOLING`
+pub const SQL_CP_OFF: SQLUINTEGER = 0;
+pub const SQL_CP_ONE_PER_DRIVER: SQLUINTEGER = 1;
+pub const SQL_CP_ONE_PER_HENV: SQLUINTEGER = 2;
+pub const SQL_CP_DRIVER_AWARE: SQLUINTEGER = 3;
+
+// Statement attributes
+pub const SQL_ATTR_ASYNC_ENABLE: SQLINTEGER = 4;
+pub const SQL_ATTR_QUERY_TIMEOUT: SQLINTEGER = 0;
+pub const SQL_ATTR_CURSOR_SENSITIVITY: SQLINTEGER = -2;
+pub const SQL_ATTR_CURSOR_SCROLLABLE: SQLINTEGER = -1;
+pub const SQL_ATTR_CURSOR_TYPE: SQLINTEGER = 6;
+pub const SQL_ATTR_CONCURRENCY: SQLINTEGER = 7;
+pub const SQL_ATTR_USE_BOOKMARKS: SQLINTEGER = 12;
+pub const SQL_ATTR_ROW_BIND_TYPE: SQLINTEGER = 5;
+pub const SQL_ATTR_PARAM_BIND_TYPE: SQLINTEGER = 18;
+pub const SQL_ATTR_PARAM_STATUS_PTR: SQLINTEGER = 20;
+pub const SQL_ATTR_PARAMS_PROCESSED_PTR: SQLINTEGER = 21;
+pub const SQL_ATTR_PARAMSET_SIZE: SQLINTEGER = 22;
+pub const SQL_ATTR_ROW_STATUS_PTR: SQLINTEGER = 25;
+pub const SQL_ATTR_ROWS_FETCHED_PTR: SQLINTEGER = 26;
+pub const SQL_ATTR_ROW_ARRAY_SIZE: SQLINTEGER = 27;
+pub const SQL_ATTR_APP_ROW_DESC: SQLINTEGER = 10010;
+pub const SQL_ATTR_APP_PARAM_DESC: SQLINTEGER = 10011;
+pub const SQL_ATTR_IMP_ROW_DESC: SQLINTEGER = 10012;
+pub const SQL_ATTR_IMP_PARAM_DESC: SQLINTEGER = 10013;
+
+/// Value of `SQL_ATTR_ROW_BIND_TYPE` and `SQL_ATTR_PARAM_BIND_TYPE` for column wise binding.
+pub const SQL_BIND_BY_COLUMN: SQLULEN = 0;
+
+// Values of `SQL_ATTR_CURSOR_SCROLLABLE`
+pub const SQL_NONSCROLLABLE: SQLULEN = 0;
+pub const SQL_SCROLLABLE: SQLULEN = 1;
+
+/// Value of `SQL_ATTR_CURSOR_TYPE` for forward only cursors
+pub const SQL_CURSOR_FORWARD_ONLY: SQLULEN = 0;
+
+// Values of `SQL_ATTR_ASYNC_ENABLE`
+pub const SQL_ASYNC_ENABLE_OFF: SQLULEN = 0;
+pub const SQL_ASYNC_ENABLE_ON: SQLULEN = 1;
+
+// Values of `SQL_ATTR_USE_BOOKMARKS`
+pub const SQL_UB_OFF: SQLULEN = 0;
+pub const SQL_UB_VARIABLE: SQLULEN = 2;
+
+// Verbose data type of intervals, which `SqlDataType` does not cover
+pub const SQL_INTERVAL: SQLSMALLINT = 10;
+
+// Length indicators of data-at-execution parameters
+pub const SQL_DATA_AT_EXEC: SQLLEN = -2;
+pub const SQL_LEN_DATA_AT_EXEC_OFFSET: SQLLEN = -100;
+
+extern "system" {
+    pub fn SQLSetStmtAttr(
+        statement_handle: SQLHSTMT,
+        attribute: SQLINTEGER,
+        value: SQLPOINTER,
+        string_length: SQLINTEGER,
+    ) -> SQLRETURN;
+
+    pub fn SQLGetStmtAttr(
+        statement_handle: SQLHSTMT,
+        attribute: SQLINTEGER,
+        value: SQLPOINTER,
+        buffer_length: SQLINTEGER,
+        string_length: *mut SQLINTEGER,
+    ) -> SQLRETURN;
+
+    pub fn SQLGetConnectAttr(
+        connection_handle: SQLHDBC,
+        attribute: SQLINTEGER,
+        value: SQLPOINTER,
+        buffer_length: SQLINTEGER,
+        string_length: *mut SQLINTEGER,
+    ) -> SQLRETURN;
+
+    pub fn SQLGetFunctions(
+        connection_handle: SQLHDBC,
+        function_id: SQLUSMALLINT,
+        supported: *mut SQLUSMALLINT,
+    ) -> SQLRETURN;
+
+    pub fn SQLGetInfo(
+        connection_handle: SQLHDBC,
+        info_type: SQLUSMALLINT,
+        info_value: SQLPOINTER,
+        buffer_length: SQLSMALLINT,
+        string_length: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    pub fn SQLSetPos(
+        statement_handle: SQLHSTMT,
+        row_number: SQLULEN,
+        operation: SQLUSMALLINT,
+        lock_type: SQLUSMALLINT,
+    ) -> SQLRETURN;
+
+    pub fn SQLColumns(
+        statement_handle: SQLHSTMT,
+        catalog_name: *const SQLCHAR,
+        catalog_name_length: SQLSMALLINT,
+        schema_name: *const SQLCHAR,
+        schema_name_length: SQLSMALLINT,
+        table_name: *const SQLCHAR,
+        table_name_length: SQLSMALLINT,
+        column_name: *const SQLCHAR,
+        column_name_length: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    pub fn SQLTablePrivileges(
+        statement_handle: SQLHSTMT,
+        catalog_name: *const SQLCHAR,
+        catalog_name_length: SQLSMALLINT,
+        schema_name: *const SQLCHAR,
+        schema_name_length: SQLSMALLINT,
+        table_name: *const SQLCHAR,
+        table_name_length: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    pub fn SQLColumnPrivileges(
+        statement_handle: SQLHSTMT,
+        catalog_name: *const SQLCHAR,
+        catalog_name_length: SQLSMALLINT,
+        schema_name: *const SQLCHAR,
+        schema_name_length: SQLSMALLINT,
+        table_name: *const SQLCHAR,
+        table_name_length: SQLSMALLINT,
+        column_name: *const SQLCHAR,
+        column_name_length: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    pub fn SQLPrimaryKeys(
+        statement_handle: SQLHSTMT,
+        catalog_name: *const SQLCHAR,
+        catalog_name_length: SQLSMALLINT,
+        schema_name: *const SQLCHAR,
+        schema_name_length: SQLSMALLINT,
+        table_name: *const SQLCHAR,
+        table_name_length: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    pub fn SQLForeignKeys(
+        statement_handle: SQLHSTMT,
+        pk_catalog_name: *const SQLCHAR,
+        pk_catalog_name_length: SQLSMALLINT,
+        pk_schema_name: *const SQLCHAR,
+        pk_schema_name_length: SQLSMALLINT,
+        pk_table_name: *const SQLCHAR,
+        pk_table_name_length: SQLSMALLINT,
+        fk_catalog_name: *const SQLCHAR,
+        fk_catalog_name_length: SQLSMALLINT,
+        fk_schema_name: *const SQLCHAR,
+        fk_schema_name_length: SQLSMALLINT,
+        fk_table_name: *const SQLCHAR,
+        fk_table_name_length: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    pub fn SQLStatistics(
+        statement_handle: SQLHSTMT,
+        catalog_name: *const SQLCHAR,
+        catalog_name_length: SQLSMALLINT,
+        schema_name: *const SQLCHAR,
+        schema_name_length: SQLSMALLINT,
+        table_name: *const SQLCHAR,
+        table_name_length: SQLSMALLINT,
+        unique: SQLUSMALLINT,
+        reserved: SQLUSMALLINT,
+    ) -> SQLRETURN;
+
+    pub fn SQLSpecialColumns(
+        statement_handle: SQLHSTMT,
+        identifier_type: SQLUSMALLINT,
+        catalog_name: *const SQLCHAR,
+        catalog_name_length: SQLSMALLINT,
+        schema_name: *const SQLCHAR,
+        schema_name_length: SQLSMALLINT,
+        table_name: *const SQLCHAR,
+        table_name_length: SQLSMALLINT,
+        scope: SQLUSMALLINT,
+        nullable: SQLUSMALLINT,
+    ) -> SQLRETURN;
+
+    pub fn SQLProcedures(
+        statement_handle: SQLHSTMT,
+        catalog_name: *const SQLCHAR,
+        catalog_name_length: SQLSMALLINT,
+        schema_name: *const SQLCHAR,
+        schema_name_length: SQLSMALLINT,
+        proc_name: *const SQLCHAR,
+        proc_name_length: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    pub fn SQLProcedureColumns(
+        statement_handle: SQLHSTMT,
+        catalog_name: *const SQLCHAR,
+        catalog_name_length: SQLSMALLINT,
+        schema_name: *const SQLCHAR,
+        schema_name_length: SQLSMALLINT,
+        proc_name: *const SQLCHAR,
+        proc_name_length: SQLSMALLINT,
+        column_name: *const SQLCHAR,
+        column_name_length: SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    pub fn SQLColAttribute(
+        statement_handle: SQLHSTMT,
+        column_number: SQLUSMALLINT,
+        field_identifier: SQLUSMALLINT,
+        character_attribute: SQLPOINTER,
+        buffer_length: SQLSMALLINT,
+        string_length: *mut SQLSMALLINT,
+        numeric_attribute: *mut SQLLEN,
+    ) -> SQLRETURN;
+
+    pub fn SQLNumParams(statement_handle: SQLHSTMT, param_count: *mut SQLSMALLINT) -> SQLRETURN;
+
+    pub fn SQLDescribeParam(
+        statement_handle: SQLHSTMT,
+        parameter_number: SQLUSMALLINT,
+        data_type: *mut SQLSMALLINT,
+        parameter_size: *mut SQLULEN,
+        decimal_digits: *mut SQLSMALLINT,
+        nullable: *mut SQLSMALLINT,
+    ) -> SQLRETURN;
+
+    pub fn SQLGetDescField(
+        descriptor_handle: SQLHDESC,
+        rec_number: SQLSMALLINT,
+        field_identifier: SQLSMALLINT,
+        value: SQLPOINTER,
+        buffer_length: SQLINTEGER,
+        string_length: *mut SQLINTEGER,
+    ) -> SQLRETURN;
+
+    pub fn SQLSetDescField(
+        descriptor_handle: SQLHDESC,
+        rec_number: SQLSMALLINT,
+        field_identifier: SQLSMALLINT,
+        value: SQLPOINTER,
+        buffer_length: SQLINTEGER,
+    ) -> SQLRETURN;
+
+    pub fn SQLSetDescRec(
+        descriptor_handle: SQLHDESC,
+        rec_number: SQLSMALLINT,
+        desc_type: SQLSMALLINT,
+        sub_type: SQLSMALLINT,
+        length: SQLLEN,
+        precision: SQLSMALLINT,
+        scale: SQLSMALLINT,
+        data: SQLPOINTER,
+        string_length: *mut SQLLEN,
+        indicator: *mut SQLLEN,
+    ) -> SQLRETURN;
+
+    pub fn SQLParamData(statement_handle: SQLHSTMT, value: *mut SQLPOINTER) -> SQLRETURN;
+
+    pub fn SQLPutData(
+        statement_handle: SQLHSTMT,
+        data: SQLPOINTER,
+        str_len_or_ind: SQLLEN,
+    ) -> SQLRETURN;
+}
@@ -0,0 +1,205 @@
+use sys::*;
+
+/// ODBC functions, whose support by the driver can be queried using
+/// `SupportedFunctions::supports`.
+///
+/// See [SQLGetFunctions Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlgetfunctions-function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FunctionId {
+    /// `SQLAllocHandle`. Allocates an environment, connection, statement or descriptor handle.
+    AllocHandle,
+    /// `SQLBindCol`. Binds a buffer to a result set column.
+    BindCol,
+    /// `SQLBindParameter`. Binds a buffer to a parameter marker.
+    BindParameter,
+    /// `SQLBrowseConnect`. Discovers the attributes required to connect iteratively.
+    BrowseConnect,
+    /// `SQLBulkOperations`. Performs bulk insertions and bookmark operations.
+    BulkOperations,
+    /// `SQLCancel`. Cancels the processing of a statement.
+    Cancel,
+    /// `SQLCancelHandle`. Cancels the processing of a connection or statement function.
+    CancelHandle,
+    /// `SQLCloseCursor`. Closes the cursor of a statement.
+    CloseCursor,
+    /// `SQLColAttribute`. Describes an attribute of a result set column.
+    ColAttribute,
+    /// `SQLColumnPrivileges`. Catalog function listing the privileges of columns.
+    ColumnPrivileges,
+    /// `SQLColumns`. Catalog function listing the columns of tables.
+    Columns,
+    /// `SQLCompleteAsync`. Completes a function executed asynchronously using notifications.
+    CompleteAsync,
+    /// `SQLConnect`. Connects to a data source.
+    Connect,
+    /// `SQLCopyDesc`. Copies the fields of a descriptor.
+    CopyDesc,
+    /// `SQLDataSources`. Lists the data source names.
+    DataSources,
+    /// `SQLDescribeCol`. Describes a result set column.
+    DescribeCol,
+    /// `SQLDescribeParam`. Describes a parameter marker.
+    DescribeParam,
+    /// `SQLDisconnect`. Closes the connection.
+    Disconnect,
+    /// `SQLDriverConnect`. Connects to a data source using a connection string.
+    DriverConnect,
+    /// `SQLDrivers`. Lists the installed drivers.
+    Drivers,
+    /// `SQLEndTran`. Commits or rolls back a transaction.
+    EndTran,
+    /// `SQLExecDirect`. Executes a statement text directly.
+    ExecDirect,
+    /// `SQLExecute`. Executes a prepared statement.
+    Execute,
+    /// `SQLFetch`. Fetches the next row set.
+    Fetch,
+    /// `SQLFetchScroll`. Fetches a row set at an arbitrary position of a scrollable cursor.
+    FetchScroll,
+    /// `SQLForeignKeys`. Catalog function listing foreign keys.
+    ForeignKeys,
+    /// `SQLFreeHandle`. Frees a handle.
+    FreeHandle,
+    /// `SQLFreeStmt`. Closes the cursor, unbinds columns or resets parameters of a statement.
+    FreeStmt,
+    /// `SQLGetConnectAttr`. Reads a connection attribute.
+    GetConnectAttr,
+    /// `SQLGetCursorName`. Reads the name of the cursor of a statement.
+    GetCursorName,
+    /// `SQLGetData`. Retrieves data of a single column.
+    GetData,
+    /// `SQLGetDescField`. Reads a field of a descriptor.
+    GetDescField,
+    /// `SQLGetDescRec`. Reads multiple fields of a descriptor record.
+    GetDescRec,
+    /// `SQLGetDiagField`. Reads a field of a diagnostic record.
+    GetDiagField,
+    /// `SQLGetDiagRec`. Reads a diagnostic record.
+    GetDiagRec,
+    /// `SQLGetEnvAttr`. Reads an environment attribute.
+    GetEnvAttr,
+    /// `SQLGetFunctions`. Lists the functions supported by the driver.
+    GetFunctions,
+    /// `SQLGetInfo`. Returns information about the driver and data source.
+    GetInfo,
+    /// `SQLGetStmtAttr`. Reads a statement attribute.
+    GetStmtAttr,
+    /// `SQLGetTypeInfo`. Lists the data types supported by the data source.
+    GetTypeInfo,
+    /// `SQLMoreResults`. Moves on to the next result set.
+    MoreResults,
+    /// `SQLNativeSql`. Translates a statement text into the native SQL grammar of the data source.
+    NativeSql,
+    /// `SQLNumParams`. Counts the parameter markers of a statement.
+    NumParams,
+    /// `SQLNumResultCols`. Counts the columns of a result set.
+    NumResultCols,
+    /// `SQLParamData`. Supplies data-at-execution parameters.
+    ParamData,
+    /// `SQLPrepare`. Prepares a statement for execution.
+    Prepare,
+    /// `SQLPrimaryKeys`. Catalog function listing the primary key of a table.
+    PrimaryKeys,
+    /// `SQLProcedureColumns`. Catalog function listing the parameters of procedures.
+    ProcedureColumns,
+    /// `SQLProcedures`. Catalog function listing procedures.
+    Procedures,
+    /// `SQLPutData`. Sends data-at-execution parameter values.
+    PutData,
+    /// `SQLRowCount`. Returns the number of rows affected by a statement.
+    RowCount,
+    /// `SQLSetConnectAttr`. Sets a connection attribute.
+    SetConnectAttr,
+    /// `SQLSetCursorName`. Names the cursor of a statement.
+    SetCursorName,
+    /// `SQLSetDescField`. Sets a field of a descriptor.
+    SetDescField,
+    /// `SQLSetDescRec`. Sets multiple fields of a descriptor record.
+    SetDescRec,
+    /// `SQLSetEnvAttr`. Sets an environment attribute.
+    SetEnvAttr,
+    /// `SQLSetPos`. Positions the cursor within a row set, refreshes, updates or deletes rows.
+    SetPos,
+    /// `SQLSetStmtAttr`. Sets a statement attribute.
+    SetStmtAttr,
+    /// `SQLSpecialColumns`. Catalog function listing the columns identifying a row.
+    SpecialColumns,
+    /// `SQLStatistics`. Catalog function listing statistics and indexes of a table.
+    Statistics,
+    /// `SQLTablePrivileges`. Catalog function listing the privileges of tables.
+    TablePrivileges,
+    /// `SQLTables`. Catalog function listing tables.
+    Tables,
+}
+
+impl FunctionId {
+    /// Value passed to the driver as `FunctionId` argument of `SQLGetFunctions`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        use FunctionId::*;
+        match *self {
+            BindCol => 4,
+            Cancel => 5,
+            ColAttribute => 6,
+            Connect => 7,
+            DescribeCol => 8,
+            Disconnect => 9,
+            ExecDirect => 11,
+            Execute => 12,
+            Fetch => 13,
+            FreeStmt => 16,
+            GetCursorName => 17,
+            NumResultCols => 18,
+            Prepare => 19,
+            RowCount => 20,
+            SetCursorName => 21,
+            BulkOperations => 24,
+            Columns => 40,
+            DriverConnect => 41,
+            GetData => 43,
+            GetFunctions => 44,
+            GetInfo => 45,
+            GetTypeInfo => 47,
+            ParamData => 48,
+            PutData => 49,
+            SpecialColumns => 52,
+            Statistics => 53,
+            Tables => 54,
+            BrowseConnect => 55,
+            ColumnPrivileges => 56,
+            DataSources => 57,
+            DescribeParam => 58,
+            ForeignKeys => 60,
+            MoreResults => 61,
+            NativeSql => 62,
+            NumParams => 63,
+            PrimaryKeys => 65,
+            ProcedureColumns => 66,
+            Procedures => 67,
+            SetPos => 68,
+            TablePrivileges => 70,
+            Drivers => 71,
+            BindParameter => 72,
+            AllocHandle => 1001,
+            CloseCursor => 1003,
+            CopyDesc => 1004,
+            EndTran => 1005,
+            FreeHandle => 1006,
+            GetConnectAttr => 1007,
+            GetDescField => 1008,
+            GetDescRec => 1009,
+            GetDiagField => 1010,
+            GetDiagRec => 1011,
+            GetEnvAttr => 1012,
+            GetStmtAttr => 1014,
+            SetConnectAttr => 1016,
+            SetDescField => 1017,
+            SetDescRec => 1018,
+            SetEnvAttr => 1019,
+            SetStmtAttr => 1020,
+            FetchScroll => 1021,
+            CancelHandle => 1550,
+            CompleteAsync => 1551,
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use super::*;
 use sys::*;
+use ffi::{SQLGetConnectAttr, SQLGetFunctions, SQLGetInfo, SQL_API_ODBC3_ALL_FUNCTIONS,
+          SQL_API_ODBC3_ALL_FUNCTIONS_SIZE};
 use std::marker::PhantomData;
-use std::mem::forget;
+use std::mem::{forget, size_of};
 use std::ptr::null_mut;
 use std::thread::panicking;
 
@@ -84,15 +86,17 @@ impl<'env> HDbc<'env> {
         P: SqlStr + ?Sized,
     {
         unsafe {
-            SQLConnect(
-                self.handle,
-                data_source_name.as_text_ptr(),
-                data_source_name.text_length(),
-                user.as_text_ptr(),
-                user.text_length(),
-                pwd.as_text_ptr(),
-                pwd.text_length(),
-            ).into()
+            until_done(|| {
+                SQLConnect(
+                    self.handle,
+                    data_source_name.as_text_ptr(),
+                    data_source_name.text_length(),
+                    user.as_text_ptr(),
+                    user.text_length(),
+                    pwd.as_text_ptr(),
+                    pwd.text_length(),
+                )
+            }).into()
         }
     }
 
@@ -108,60 +112,202 @@ impl<'env> HDbc<'env> {
         unsafe {
             let window_handle = null_mut();
             let mut out_connection_string_len = 0;
-            let ret: Return<()> = SQLDriverConnect(
-                self.handle,
-                window_handle,
-                in_connection_string.as_text_ptr(),
-                in_connection_string.text_length(),
-                out_connection_string.mut_buf_ptr(),
-                out_connection_string.buf_len(),
-                &mut out_connection_string_len,
-                driver_completion,
-            ).into();
+            let ret: Return<()> = until_done(|| {
+                SQLDriverConnect(
+                    self.handle,
+                    window_handle,
+                    in_connection_string.as_text_ptr(),
+                    in_connection_string.text_length(),
+                    out_connection_string.mut_buf_ptr(),
+                    out_connection_string.buf_len(),
+                    &mut out_connection_string_len,
+                    driver_completion,
+                )
+            }).into();
             ret.map(|()| out_connection_string_len)
         }
     }
 
+    /// Same as `connect`, but returns `StillExecuting` rather than blocking, if asynchronous
+    /// execution of connection functions is enabled. Must be called again with the same arguments
+    /// until it is complete.
+    pub fn connect_async<DSN, U, P>(
+        &mut self,
+        data_source_name: &DSN,
+        user: &U,
+        pwd: &P,
+    ) -> ReturnAsync<()>
+    where
+        DSN: SqlStr + ?Sized,
+        U: SqlStr + ?Sized,
+        P: SqlStr + ?Sized,
+    {
+        unsafe {
+            SQLConnect(
+                self.handle,
+                data_source_name.as_text_ptr(),
+                data_source_name.text_length(),
+                user.as_text_ptr(),
+                user.text_length(),
+                pwd.as_text_ptr(),
+                pwd.text_length(),
+            ).into()
+        }
+    }
+
+    /// Enables or disables asynchronous execution of connection functions like `connect_async`
+    pub fn set_async_dbc_functions_enable(&mut self, enabled: bool) -> Return<()> {
+        let value = if enabled {
+            SQL_ASYNC_DBC_ENABLE_ON
+        } else {
+            SQL_ASYNC_DBC_ENABLE_OFF
+        };
+        unsafe {
+            until_done(|| {
+                SQLSetConnectAttr(
+                    self.handle,
+                    SQL_ATTR_ASYNC_DBC_FUNCTIONS_ENABLE,
+                    value as usize as SQLPOINTER,
+                    0,
+                )
+            }).into()
+        }
+    }
+
+    /// Cancels a connection function executing asynchronously
+    pub fn cancel(&mut self) -> Return<()> {
+        unsafe { SQLCancelHandle(SQL_HANDLE_DBC, self.handle as SQLHANDLE).into() }
+    }
+
     pub fn disconnect(&mut self) -> Return<()> {
-        unsafe { SQLDisconnect(self.handle).into() }
+        unsafe { until_done(|| SQLDisconnect(self.handle)).into() }
     }
 
     pub fn set_autocommit(&mut self, enabled: bool) -> Return<()> {
         let val = if enabled { 1u32 } else { 0u32 };
         unsafe {
-            SQLSetConnectAttr(
-                self.handle,
-                SQL_ATTR_AUTOCOMMIT,
-                val as SQLPOINTER,
-                0 // will be ignored according to ODBC spec
-            ).into()
+            until_done(|| {
+                SQLSetConnectAttr(
+                    self.handle,
+                    SQL_ATTR_AUTOCOMMIT,
+                    val as SQLPOINTER,
+                    0 // will be ignored according to ODBC spec
+                )
+            }).into()
         }
     }
 
+    /// Number of seconds to wait for a login request to complete. `0` waits indefinitely.
+    pub fn set_login_timeout(&mut self, seconds: SQLUINTEGER) -> Return<()> {
+        unsafe {
+            until_done(|| {
+                SQLSetConnectAttr(self.handle, SQL_ATTR_LOGIN_TIMEOUT, seconds as SQLPOINTER, 0)
+            }).into()
+        }
+    }
+
+    /// Number of seconds to wait for any request on the connection, other than query execution and
+    /// login, to complete. `0` waits indefinitely.
+    pub fn set_connection_timeout(&mut self, seconds: SQLUINTEGER) -> Return<()> {
+        unsafe {
+            until_done(|| {
+                let value = seconds as SQLPOINTER;
+                SQLSetConnectAttr(self.handle, SQL_ATTR_CONNECTION_TIMEOUT, value, 0)
+            }).into()
+        }
+    }
+
+    /// Sets the transaction isolation level. Must not be called while a transaction is open.
+    pub fn set_isolation_level(&mut self, level: SQLUINTEGER) -> Return<()> {
+        unsafe {
+            until_done(|| {
+                SQLSetConnectAttr(self.handle, SQL_ATTR_TXN_ISOLATION, level as SQLPOINTER, 0)
+            }).into()
+        }
+    }
+
+    /// Current transaction isolation level
+    pub fn isolation_level(&self) -> Return<SQLUINTEGER> {
+        let mut out: SQLUINTEGER = 0;
+        let value: *mut SQLUINTEGER = &mut out;
+        let ret: Return<()> = unsafe {
+            until_done(|| {
+                let value = value as SQLPOINTER;
+                let attribute = SQL_ATTR_TXN_ISOLATION as SQLINTEGER;
+                SQLGetConnectAttr(self.handle, attribute, value, 0, null_mut())
+            }).into()
+        };
+        ret.map(|()| out)
+    }
+
     pub fn commit(&mut self) -> Return<()> {
-        unsafe {  SQLEndTran(SQL_HANDLE_DBC, self.handle as *mut Obj, SQL_COMMIT).into() }
+        unsafe {
+            until_done(|| SQLEndTran(SQL_HANDLE_DBC, self.handle as *mut Obj, SQL_COMMIT)).into()
+        }
     }
 
     pub fn rollback(&mut self) -> Return<()> {
-        unsafe { SQLEndTran(SQL_HANDLE_DBC, self.handle as *mut Obj, SQL_ROLLBACK).into() }
+        unsafe {
+            until_done(|| SQLEndTran(SQL_HANDLE_DBC, self.handle as *mut Obj, SQL_ROLLBACK)).into()
+        }
     }
 
-    /// Returns wether the data source is read only
-    pub fn is_read_only(&mut self) -> Return<bool> {
-        unsafe {
-            let mut buffer = [0; 2];
-            let ret: Return<()> = SQLGetInfo(
+    /// Reads a fixed size information value about the driver or data source. `T` must match the
+    /// size of the value returned for `info_type`.
+    pub fn info<T>(&self, info_type: SQLUSMALLINT) -> Return<T>
+    where
+        T: Copy + Default,
+    {
+        let mut out = T::default();
+        let value: *mut T = &mut out;
+        let ret: Return<()> = unsafe {
+            SQLGetInfo(
                 self.handle,
-                SQL_DATA_SOURCE_READ_ONLY,
-                buffer.as_mut_ptr() as SQLPOINTER,
-                buffer.buf_len(),
+                info_type,
+                value as SQLPOINTER,
+                size_of::<T>() as SQLSMALLINT,
                 null_mut(),
-            ).into();
-            ret.map(|()| match buffer[0] as char {
-                'N' => false,
-                'Y' => true,
-                _ => panic!(r#"Briver may only return "N" or "Y""#),
-            })
+            ).into()
+        };
+        ret.map(|()| out)
+    }
+
+    /// Reads a character string information value about the driver or data source. The buffer
+    /// grows until the complete value fits.
+    pub fn string_info(&self, info_type: SQLUSMALLINT) -> Return<String> {
+        let mut buffer = vec![0u8; 64];
+        loop {
+            let mut string_length = 0;
+            let ret: Return<()> = unsafe {
+                SQLGetInfo(
+                    self.handle,
+                    info_type,
+                    buffer.as_mut_ptr() as SQLPOINTER,
+                    buffer.buf_len(),
+                    &mut string_length,
+                ).into()
+            };
+            if let Error(()) = ret {
+                return Error(());
+            }
+            // Room is required for the terminating zero
+            let string_length = string_length.max(0) as usize;
+            if string_length >= buffer.len() {
+                buffer.resize(string_length + 1, 0);
+                continue;
+            }
+            buffer.truncate(string_length);
+            return ret.map(|()| String::from_utf8_lossy(&buffer).into_owned());
+        }
+    }
+
+    /// Fills `supported` with a bitset of all ODBC 3 functions supported by the driver
+    pub fn supported_functions(
+        &self,
+        supported: &mut [SQLUSMALLINT; SQL_API_ODBC3_ALL_FUNCTIONS_SIZE],
+    ) -> Return<()> {
+        unsafe {
+            SQLGetFunctions(self.handle, SQL_API_ODBC3_ALL_FUNCTIONS, supported.as_mut_ptr()).into()
         }
     }
 }
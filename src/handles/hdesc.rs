@@ -0,0 +1,188 @@
+use super::*;
+use ffi::{SQLGetDescField, SQLSetDescField, SQLSetDescRec, SQL_INTERVAL};
+use sys::*;
+use std::marker::PhantomData;
+use std::mem::size_of;
+use std::ptr::null_mut;
+use std::thread::panicking;
+
+#[derive(Debug)]
+pub struct HDesc<'con> {
+    /// Descriptor may not outlive the connection used to allocate it.
+    parent: PhantomData<&'con HDbc<'con>>,
+    /// Invariant: Descriptor handle is always valid.
+    handle: SQLHDESC,
+    /// Implicitly allocated descriptors are owned by their statement and freed along with it.
+    implicit: bool,
+}
+
+impl<'con> Drop for HDesc<'con> {
+    fn drop(&mut self) {
+        if self.implicit {
+            return;
+        }
+        unsafe {
+            match SQLFreeHandle(SQL_HANDLE_DESC, self.handle as SQLHANDLE) {
+                SQL_SUCCESS => (),
+                other => {
+                    if !panicking() {
+                        panic!("Unexepected return value of SQLFreeHandle: {:?}.", other)
+                    }
+                }
+            }
+        }
+    }
+}
+
+unsafe impl<'con> Handle for HDesc<'con> {
+    const HANDLE_TYPE: HandleType = SQL_HANDLE_DESC;
+
+    fn handle(&self) -> SQLHANDLE {
+        self.handle as SQLHANDLE
+    }
+}
+
+impl<'con> HDesc<'con> {
+    /// Provides access to the raw ODBC Descriptor Handle
+    pub fn as_raw(&self) -> SQLHDESC {
+        self.handle
+    }
+
+    /// Allocates a new explicit Descriptor Handle
+    pub fn allocate(parent: &HDbc<'con>) -> Return<Self> {
+        let mut out = null_mut();
+        unsafe {
+            let result: Return<()> = SQLAllocHandle(SQL_HANDLE_DESC, parent.handle(), &mut out)
+                .into();
+            result.map(|()| {
+                HDesc {
+                    parent: PhantomData,
+                    handle: out as SQLHDESC,
+                    implicit: false,
+                }
+            })
+        }
+    }
+
+    /// Wraps a descriptor implicitly allocated for a statement. It is not freed on drop.
+    ///
+    /// May only be invoked with a valid Descriptor Handle. The caller must make sure it does not
+    /// outlive its statement.
+    pub unsafe fn implicit(raw: SQLHDESC) -> Self {
+        HDesc {
+            parent: PhantomData,
+            handle: raw,
+            implicit: true,
+        }
+    }
+
+    /// Reads a fixed size field of the descriptor. `T` must match the size of the field.
+    pub fn field<T>(&self, rec_number: SQLSMALLINT, field_identifier: SQLSMALLINT) -> Return<T>
+    where
+        T: Copy + Default,
+    {
+        let mut out = T::default();
+        let value: *mut T = &mut out;
+        let ret: Return<()> = unsafe {
+            SQLGetDescField(
+                self.handle,
+                rec_number,
+                field_identifier,
+                value as SQLPOINTER,
+                size_of::<T>() as SQLINTEGER,
+                null_mut(),
+            ).into()
+        };
+        ret.map(|()| out)
+    }
+
+    /// Reads a character field of the descriptor. Returns the length of the field in bytes.
+    pub fn string_field<T>(
+        &self,
+        rec_number: SQLSMALLINT,
+        field_identifier: SQLSMALLINT,
+        value: &mut T,
+    ) -> Return<SQLINTEGER>
+    where
+        T: OutputBuffer + ?Sized,
+    {
+        let mut string_length = 0;
+        let ret: Return<()> = unsafe {
+            SQLGetDescField(
+                self.handle,
+                rec_number,
+                field_identifier,
+                value.mut_buf_ptr() as SQLPOINTER,
+                value.buf_len(),
+                &mut string_length,
+            ).into()
+        };
+        ret.map(|()| string_length)
+    }
+
+    /// Sets an integer field of the descriptor. Fields may describe buffers used by a statement,
+    /// so it is the callers responsibility to keep these consistent.
+    pub unsafe fn set_integer_field(
+        &mut self,
+        rec_number: SQLSMALLINT,
+        field_identifier: SQLSMALLINT,
+        value: SQLLEN,
+    ) -> Return<()> {
+        SQLSetDescField(self.handle, rec_number, field_identifier, value as SQLPOINTER, 0).into()
+    }
+
+    /// Describes a buffer bound to a record of the descriptor.
+    ///
+    /// It is the callers responsibility to make sure the buffers live long enough.
+    ///
+    /// # Panics
+    /// If `T` is `SQL_C_NUMERIC`, which requires precision and scale.
+    pub unsafe fn set_rec<T>(
+        &mut self,
+        rec_number: SQLSMALLINT,
+        value: &mut T,
+        indicator: &mut SQLLEN,
+    ) -> Return<()>
+    where
+        T: CDataType + ?Sized,
+    {
+        let (desc_type, sub_type) = verbose_type(T::c_data_type());
+        SQLSetDescRec(
+            self.handle,
+            rec_number,
+            desc_type,
+            sub_type,
+            value.buffer_len(),
+            0,
+            0,
+            value.mut_sql_ptr(),
+            indicator,
+            indicator,
+        ).into()
+    }
+
+    /// Copies all fields of `source` into this descriptor. Buffers referenced by `source` are
+    /// referenced by this descriptor afterwards.
+    pub unsafe fn copy_from(&mut self, source: &HDesc) -> Return<()> {
+        SQLCopyDesc(source.handle, self.handle).into()
+    }
+}
+
+/// Splits a concise C data type into the verbose type and the datetime interval code, as expected
+/// by `SQLSetDescRec`.
+fn verbose_type(concise: SqlCDataType) -> (SQLSMALLINT, SQLSMALLINT) {
+    assert!(
+        concise != SQL_C_NUMERIC,
+        "Numeric records require precision and scale. Set them using `set_field`."
+    );
+    let concise = concise as SQLSMALLINT;
+    match concise {
+        // `SQL_C_DATE`, `SQL_C_TIME` and `SQL_C_TIMESTAMP`
+        9..=11 => (SQL_DATETIME as SQLSMALLINT, concise - 8),
+        // `SQL_C_TYPE_DATE`, `SQL_C_TYPE_TIME` and `SQL_C_TYPE_TIMESTAMP`
+        91..=93 => (SQL_DATETIME as SQLSMALLINT, concise - 90),
+        // `SQL_C_INTERVAL_YEAR` up to `SQL_C_INTERVAL_MINUTE_TO_SECOND`
+        101..=113 => (SQL_INTERVAL, concise - 100),
+        _ => (concise, 0),
+    }
+}
@@ -58,6 +58,28 @@ impl HEnv {
         unsafe { SQLSetEnvAttr(self.handle, SQL_ATTR_ODBC_VERSION, version.into(), 0).into() }
     }
 
+    /// Enables or disables connection pooling for the whole process. Must be called before any
+    /// environment is allocated.
+    pub fn set_process_connection_pooling(pooling: SQLUINTEGER) -> Return<()> {
+        unsafe {
+            SQLSetEnvAttr(null_mut(), SQL_ATTR_CONNECTION_POOLING, pooling as SQLPOINTER, 0).into()
+        }
+    }
+
+    /// Enables or disables driver-aware connection pooling for this environment. Must be set
+    /// before any connection is allocated.
+    pub fn set_connection_pooling(&mut self, pooling: SQLUINTEGER) -> Return<()> {
+        unsafe {
+            SQLSetEnvAttr(self.handle, SQL_ATTR_CONNECTION_POOLING, pooling as SQLPOINTER, 0)
+                .into()
+        }
+    }
+
+    /// Sets how connections are matched against the connections in the pool
+    pub fn set_cp_match(&mut self, cp_match: SQLUINTEGER) -> Return<()> {
+        unsafe { SQLSetEnvAttr(self.handle, SQL_ATTR_CP_MATCH, cp_match as SQLPOINTER, 0).into() }
+    }
+
     /// Fills buffers and returns `(name_length, description_length)`
     pub fn data_sources(
         &mut self,
@@ -1,6 +1,17 @@
 use super::*;
+use ffi::{SQLColAttribute, SQLColumnPrivileges, SQLColumns, SQLDescribeParam, SQLForeignKeys, SQLGetStmtAttr,
+          SQLNumParams, SQLParamData, SQLPrimaryKeys, SQLProcedureColumns, SQLProcedures, SQLPutData, SQLSetPos,
+          SQLSetStmtAttr, SQLSpecialColumns, SQLStatistics, SQLTablePrivileges,
+          SQL_ASYNC_ENABLE_OFF, SQL_ASYNC_ENABLE_ON, SQL_ATTR_ASYNC_ENABLE,
+          SQL_ATTR_APP_PARAM_DESC, SQL_ATTR_APP_ROW_DESC, SQL_ATTR_PARAM_BIND_TYPE,
+          SQL_ATTR_PARAM_STATUS_PTR, SQL_ATTR_PARAMSET_SIZE, SQL_ATTR_PARAMS_PROCESSED_PTR,
+          SQL_ATTR_ROW_ARRAY_SIZE, SQL_ATTR_ROW_BIND_TYPE, SQL_ATTR_ROW_STATUS_PTR,
+          SQL_ATTR_ROWS_FETCHED_PTR, SQL_ATTR_QUERY_TIMEOUT, SQL_BIND_BY_COLUMN};
 use sys::*;
+use cancel_handle::{CancelHandle, SharedHandle};
+use sql_str::{self, OwnedSqlStr};
 use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
 use std::ptr::{null, null_mut};
 use std::thread::panicking;
 
@@ -10,12 +21,59 @@ pub struct HStmt<'con> {
     parent: PhantomData<&'con HDbc<'con>>,
     /// Invariant: Connection handle is always valid.
     handle: SQLHSTMT,
+    /// Shared with cancel handles. Only allocated once a cancel handle is requested.
+    shared: Option<Arc<Mutex<SharedHandle>>>,
+    /// Function executing asynchronously, which must be polled until it is complete.
+    pending: Option<PendingCall>,
+    /// The last execution has been canceled, because the driver asked for data-at-execution
+    /// parameters.
+    rejected_need_data: bool,
+}
+
+/// A function returned `SQL_STILL_EXECUTING` and has to be called again, until it returns
+/// something else.
+#[derive(Debug)]
+enum PendingCall {
+    /// Holds a copy of the statement text, since it must be passed again on each call.
+    ExecDirect(OwnedSqlStr),
+    Execute,
+    Fetch,
+    MoreResults,
+}
+
+impl PendingCall {
+    unsafe fn call(&self, handle: SQLHSTMT) -> SQLRETURN {
+        match *self {
+            PendingCall::ExecDirect(ref text) => {
+                SQLExecDirect(handle, text.as_text_ptr(), text.text_length_int())
+            }
+            PendingCall::Execute => SQLExecute(handle),
+            PendingCall::Fetch => SQLFetch(handle),
+            PendingCall::MoreResults => SQLMoreResults(handle),
+        }
+    }
 }
 
 impl<'con, 'param> Drop for HStmt<'con> {
     fn drop(&mut self) {
+        if let Some(ref shared) = self.shared {
+            SharedHandle::invalidate(shared);
+        }
         unsafe {
-            match SQLFreeHandle(SQL_HANDLE_STMT, self.handle as SQLHANDLE) {
+            if let Some(pending) = self.pending.take() {
+                // An asynchronously executing function must complete before the statement can be
+                // freed.
+                let _ = SQLCancel(self.handle);
+                let _ = until_done(|| pending.call(self.handle));
+            }
+            let mut ret = SQLFreeHandle(SQL_HANDLE_STMT, self.handle as SQLHANDLE);
+            if ret == SQL_ERROR {
+                // A statement still waiting for data-at-execution parameters can not be freed
+                // until the execution is canceled.
+                let _ = SQLCancel(self.handle);
+                ret = SQLFreeHandle(SQL_HANDLE_STMT, self.handle as SQLHANDLE);
+            }
+            match ret {
                 SQL_SUCCESS => (),
                 other => {
                     if !panicking() {
@@ -50,6 +108,9 @@ impl<'env, 'param> HStmt<'env> {
                 HStmt {
                     parent: PhantomData,
                     handle: out as SQLHSTMT,
+                    shared: None,
+                    pending: None,
+                    rejected_need_data: false,
                 }
             })
         }
@@ -59,18 +120,109 @@ impl<'env, 'param> HStmt<'env> {
     where
         T: SqlStr + ?Sized,
     {
-        unsafe {
+        let ret = unsafe {
+            until_done(|| {
+                SQLExecDirect(
+                    self.handle,
+                    statement_text.as_text_ptr(),
+                    statement_text.text_length_int(),
+                )
+            })
+        };
+        self.reject_need_data(ret).into()
+    }
+
+    /// Same as `exec_direct`, but returns `StillExecuting` rather than blocking, if asynchronous
+    /// execution is enabled. Use `poll` to drive the execution to completion.
+    pub fn exec_direct_async<T>(&mut self, statement_text: &T) -> ReturnAsync<()>
+    where
+        T: SqlStr + ?Sized,
+    {
+        let ret = unsafe {
             SQLExecDirect(
                 self.handle,
                 statement_text.as_text_ptr(),
                 statement_text.text_length_int(),
-            ).into()
+            )
+        };
+        let ret = self.reject_need_data(ret);
+        self.track(ret, || PendingCall::ExecDirect(sql_str::to_owned(statement_text)))
+    }
+
+    /// Same as `execute`, but returns `StillExecuting` rather than blocking, if asynchronous
+    /// execution is enabled. Use `poll` to drive the execution to completion.
+    pub fn execute_async(&mut self) -> ReturnAsync<()> {
+        let ret = unsafe { SQLExecute(self.handle) };
+        let ret = self.reject_need_data(ret);
+        self.track(ret, || PendingCall::Execute)
+    }
+
+    /// Same as `fetch`, but returns `StillExecuting` rather than blocking, if asynchronous
+    /// execution is enabled. Use `poll` to drive the fetch to completion.
+    pub fn fetch_async(&mut self) -> ReturnAsync<()> {
+        let ret = unsafe { SQLFetch(self.handle) };
+        self.track(ret, || PendingCall::Fetch)
+    }
+
+    /// Same as `more_results`, but returns `StillExecuting` rather than blocking, if asynchronous
+    /// execution is enabled. Use `poll` to drive the function to completion.
+    pub fn more_results_async(&mut self) -> ReturnAsync<()> {
+        let ret = unsafe { SQLMoreResults(self.handle) };
+        self.track(ret, || PendingCall::MoreResults)
+    }
+
+    /// Calls the function which previously returned `StillExecuting` again.
+    ///
+    /// # Panics
+    /// If no function is executing asynchronously.
+    pub fn poll(&mut self) -> ReturnAsync<()> {
+        let ret = unsafe {
+            self.pending
+                .as_ref()
+                .expect("No function is executing asynchronously")
+                .call(self.handle)
+        };
+        if ret != SQL_STILL_EXECUTING {
+            self.pending = None;
         }
+        self.reject_need_data(ret).into()
+    }
+
+    /// Parameters bound as data-at-execution can only be sent using `exec_direct_at_exec` or
+    /// `execute_at_exec`. Should the driver ask for their data during any other execution, the
+    /// execution is canceled and reported as an error.
+    fn reject_need_data(&mut self, ret: SQLRETURN) -> SQLRETURN {
+        self.rejected_need_data = ret == SQL_NEED_DATA;
+        if self.rejected_need_data {
+            // Returns the statement to the state it had been in before the execution
+            unsafe {
+                let _ = SQLCancel(self.handle);
+            }
+            SQL_ERROR
+        } else {
+            ret
+        }
+    }
+
+    /// `true` if the last execution has been canceled by `reject_need_data`
+    pub fn rejected_need_data(&self) -> bool {
+        self.rejected_need_data
+    }
+
+    /// Remembers the function to `poll`, if it is still executing.
+    fn track<F>(&mut self, ret: SQLRETURN, pending: F) -> ReturnAsync<()>
+    where
+        F: FnOnce() -> PendingCall,
+    {
+        if ret == SQL_STILL_EXECUTING {
+            self.pending = Some(pending());
+        }
+        ret.into()
     }
 
     pub fn num_result_cols(&self) -> Return<SQLSMALLINT> {
         let mut out: SQLSMALLINT = 0;
-        let ret = unsafe { SQLNumResultCols(self.handle, &mut out) };
+        let ret = unsafe { until_done(|| SQLNumResultCols(self.handle, &mut out)) };
         let ret: Return<()> = ret.into();
         ret.map(|()| out)
     }
@@ -83,7 +235,7 @@ impl<'env, 'param> HStmt<'env> {
     }
 
     pub fn fetch(&mut self) -> ReturnOption<()> {
-        unsafe { SQLFetch(self.handle).into() }
+        unsafe { until_done(|| SQLFetch(self.handle)).into() }
     }
 
     pub fn get_data<T>(
@@ -96,18 +248,78 @@ impl<'env, 'param> HStmt<'env> {
     {
         let mut str_len_or_ind = 0;
         let ret: ReturnOption<()> = unsafe {
-            SQLGetData(
-                self.handle,
-                col_or_param_num,
-                T::c_data_type(),
-                target.mut_sql_ptr(),
-                target.buffer_len(),
-                &mut str_len_or_ind,
-            ).into()
+            until_done(|| {
+                SQLGetData(
+                    self.handle,
+                    col_or_param_num,
+                    T::c_data_type(),
+                    target.mut_sql_ptr(),
+                    target.buffer_len(),
+                    &mut str_len_or_ind,
+                )
+            }).into()
+        };
+        ret.map(|()| str_len_or_ind.into())
+    }
+
+    /// Retrieves the next chunk of a column as `target_type` into `buffer`
+    pub fn get_data_chunk(
+        &mut self,
+        col_or_param_num: SQLUSMALLINT,
+        target_type: SqlCDataType,
+        buffer: &mut [u8],
+    ) -> ReturnOption<Indicator> {
+        let mut str_len_or_ind = 0;
+        let ret: ReturnOption<()> = unsafe {
+            until_done(|| {
+                SQLGetData(
+                    self.handle,
+                    col_or_param_num,
+                    target_type,
+                    buffer.as_mut_ptr() as SQLPOINTER,
+                    buffer.len() as SQLLEN,
+                    &mut str_len_or_ind,
+                )
+            }).into()
         };
         ret.map(|()| str_len_or_ind.into())
     }
 
+    pub fn fetch_scroll(
+        &mut self,
+        fetch_orientation: FetchOrientation,
+        fetch_offset: SQLLEN,
+    ) -> ReturnOption<()> {
+        unsafe {
+            until_done(|| SQLFetchScroll(self.handle, fetch_orientation, fetch_offset)).into()
+        }
+    }
+
+    /// Performs `operation` on a row of the current row set. `0` refers to all rows.
+    ///
+    /// Depending on the operation the driver reads from or writes to the bound buffers. It is the
+    /// callers responsibility to make sure they are still valid.
+    pub unsafe fn set_pos(
+        &mut self,
+        row_number: SQLULEN,
+        operation: SQLUSMALLINT,
+        lock_type: SQLUSMALLINT,
+    ) -> Return<()> {
+        until_done(|| SQLSetPos(self.handle, row_number, operation, lock_type)).into()
+    }
+
+    /// Performs a bulk insertion or bookmark operation.
+    ///
+    /// The driver reads from or writes to the bound buffers. It is the callers responsibility to
+    /// make sure they are still valid.
+    pub unsafe fn bulk_operations(&mut self, operation: SqlBulkOperation) -> Return<()> {
+        until_done(|| SQLBulkOperations(self.handle, operation)).into()
+    }
+
+    pub fn more_results(&mut self) -> ReturnOption<()> {
+        unsafe { until_done(|| SQLMoreResults(self.handle)).into() }
+    }
+
     pub fn close_cursor(&mut self) -> Return<()> {
         unsafe { SQLCloseCursor(self.handle).into() }
     }
@@ -132,44 +344,555 @@ impl<'env, 'param> HStmt<'env> {
             }
             None => null(),
         };
-        SQLBindParameter(
-            self.handle,
+        self.bind_parameter_ptr(
             parameter_number,
             SQL_PARAM_INPUT,
             T::c_data_type(),
-            parameter_type.sql_data_type(),
-            parameter_type.column_size(),
-            parameter_type.decimal_digits(),
+            parameter_type,
             value.sql_ptr() as SQLPOINTER,
             0,
             indicator as *mut SQLLEN,
+        )
+    }
+
+    /// Binds a buffer to an output or input/output parameter marker in an SQL Statement
+    ///
+    /// It is the callers responsibility to make sure the bound parameters live long enough.
+    pub unsafe fn bind_output_parameter<T>(
+        &mut self,
+        parameter_number: SQLUSMALLINT,
+        input_output_type: InputOutput,
+        parameter_type: DataType,
+        value: &mut T,
+        indicator: &mut SQLLEN,
+    ) -> Return<()>
+    where
+        T: CDataType + ?Sized,
+    {
+        self.bind_parameter_ptr(
+            parameter_number,
+            input_output_type,
+            T::c_data_type(),
+            parameter_type,
+            value.mut_sql_ptr(),
+            value.buffer_len(),
+            indicator,
+        )
+    }
+
+    /// Binds a raw buffer to a parameter marker in an SQL Statement
+    ///
+    /// In case of parameter arrays `buffer_length` is the length of a single element. It is the
+    /// callers responsibility to make sure the bound buffers are large enough and live long enough.
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn bind_parameter_ptr(
+        &mut self,
+        parameter_number: SQLUSMALLINT,
+        input_output_type: InputOutput,
+        value_type: SqlCDataType,
+        parameter_type: DataType,
+        value: SQLPOINTER,
+        buffer_length: SQLLEN,
+        indicator: *mut SQLLEN,
+    ) -> Return<()> {
+        SQLBindParameter(
+            self.handle,
+            parameter_number,
+            input_output_type,
+            value_type,
+            parameter_type.sql_data_type(),
+            parameter_type.column_size(),
+            parameter_type.decimal_digits(),
+            value,
+            buffer_length,
+            indicator,
         ).into()
     }
 
+    /// Configures the statement to be executed once for each of `param_status.len()` sets of
+    /// parameters.
+    ///
+    /// It is the callers responsibility to make sure `params_processed` and `param_status` live
+    /// long enough. Should an error occur, some of the attributes may already be set.
+    pub unsafe fn bind_param_set(
+        &mut self,
+        params_processed: &mut SQLULEN,
+        param_status: &mut [SQLUSMALLINT],
+    ) -> Return<()> {
+        let params_processed: *mut SQLULEN = params_processed;
+        let attributes = [
+            (SQL_ATTR_PARAM_BIND_TYPE, SQL_BIND_BY_COLUMN as SQLPOINTER),
+            (SQL_ATTR_PARAM_STATUS_PTR, param_status.as_mut_ptr() as SQLPOINTER),
+            (SQL_ATTR_PARAMS_PROCESSED_PTR, params_processed as SQLPOINTER),
+            (SQL_ATTR_PARAMSET_SIZE, param_status.len() as SQLPOINTER),
+        ];
+        let mut result = Success(());
+        for &(attribute, value) in &attributes {
+            match self.set_attribute(attribute, value) {
+                Success(()) => (),
+                Info(()) => result = Info(()),
+                Error(()) => return Error(()),
+            }
+        }
+        result
+    }
+
+    /// Executes with a single set of parameters again and releases the buffers bound by
+    /// `bind_param_set`.
+    pub fn reset_param_set(&mut self) -> Return<()> {
+        let attributes = [
+            (SQL_ATTR_PARAMSET_SIZE, 1 as SQLPOINTER),
+            (SQL_ATTR_PARAMS_PROCESSED_PTR, null_mut()),
+            (SQL_ATTR_PARAM_STATUS_PTR, null_mut()),
+        ];
+        let mut result = Success(());
+        for &(attribute, value) in &attributes {
+            match unsafe { self.set_attribute(attribute, value) } {
+                Success(()) => (),
+                Info(()) => result = Info(()),
+                Error(()) => return Error(()),
+            }
+        }
+        result
+    }
+
+    /// Number of parameter sets the statement is executed with
+    pub fn paramset_size(&self) -> Return<SQLULEN> {
+        self.attribute(SQL_ATTR_PARAMSET_SIZE)
+    }
+
+    /// Reads the number of parameter sets processed by the last execution. `None` if no buffer
+    /// has been bound using `bind_param_set`.
+    ///
+    /// May only be called as long as the buffer bound with `bind_param_set` is valid.
+    pub unsafe fn params_processed(&self) -> Return<Option<SQLULEN>> {
+        self.attribute(SQL_ATTR_PARAMS_PROCESSED_PTR).map(|ptr| {
+            let ptr = ptr as *const SQLULEN;
+            if ptr.is_null() { None } else { Some(*ptr) }
+        })
+    }
+
+    /// Reads the status of a set of parameters. `None` if no buffer has been bound using
+    /// `bind_param_set`.
+    ///
+    /// May only be called as long as the buffer bound with `bind_param_set` is valid and
+    /// `index` is smaller than the paramset size.
+    pub unsafe fn param_status(&self, index: usize) -> Return<Option<SQLUSMALLINT>> {
+        self.attribute(SQL_ATTR_PARAM_STATUS_PTR).map(|ptr| {
+            let ptr = ptr as *const SQLUSMALLINT;
+            if ptr.is_null() { None } else { Some(*ptr.add(index)) }
+        })
+    }
+
     pub fn prepare<T>(&mut self, statement_text: &T) -> Return<()>
     where
         T: SqlStr + ?Sized,
     {
         unsafe {
-            SQLPrepare(
-                self.handle,
-                statement_text.as_text_ptr(),
-                statement_text.text_length_int(),
-            ).into()
+            until_done(|| {
+                SQLPrepare(
+                    self.handle,
+                    statement_text.as_text_ptr(),
+                    statement_text.text_length_int(),
+                )
+            }).into()
+        }
+    }
+
+    pub fn tables<C, S, T, Y>(
+        &mut self,
+        catalog_name: &C,
+        schema_name: &S,
+        table_name: &T,
+        table_type: &Y,
+    ) -> Return<()>
+    where
+        C: SqlStr + ?Sized,
+        S: SqlStr + ?Sized,
+        T: SqlStr + ?Sized,
+        Y: SqlStr + ?Sized,
+    {
+        unsafe {
+            until_done(|| {
+                SQLTables(
+                    self.handle,
+                    catalog_name.as_text_ptr(),
+                    catalog_name.text_length(),
+                    schema_name.as_text_ptr(),
+                    schema_name.text_length(),
+                    table_name.as_text_ptr(),
+                    table_name.text_length(),
+                    table_type.as_text_ptr(),
+                    table_type.text_length(),
+                )
+            }).into()
+        }
+    }
+
+    pub fn columns<C, S, T, N>(
+        &mut self,
+        catalog_name: &C,
+        schema_name: &S,
+        table_name: &T,
+        column_name: &N,
+    ) -> Return<()>
+    where
+        C: SqlStr + ?Sized,
+        S: SqlStr + ?Sized,
+        T: SqlStr + ?Sized,
+        N: SqlStr + ?Sized,
+    {
+        unsafe {
+            until_done(|| {
+                SQLColumns(
+                    self.handle,
+                    catalog_name.as_text_ptr(),
+                    catalog_name.text_length(),
+                    schema_name.as_text_ptr(),
+                    schema_name.text_length(),
+                    table_name.as_text_ptr(),
+                    table_name.text_length(),
+                    column_name.as_text_ptr(),
+                    column_name.text_length(),
+                )
+            }).into()
         }
     }
 
+    pub fn table_privileges<C, S, T>(
+        &mut self,
+        catalog_name: &C,
+        schema_name: &S,
+        table_name: &T,
+    ) -> Return<()>
+    where
+        C: SqlStr + ?Sized,
+        S: SqlStr + ?Sized,
+        T: SqlStr + ?Sized,
+    {
+        unsafe {
+            until_done(|| {
+                SQLTablePrivileges(
+                    self.handle,
+                    catalog_name.as_text_ptr(),
+                    catalog_name.text_length(),
+                    schema_name.as_text_ptr(),
+                    schema_name.text_length(),
+                    table_name.as_text_ptr(),
+                    table_name.text_length(),
+                )
+            }).into()
+        }
+    }
+
+    pub fn column_privileges<C, S, T, N>(
+        &mut self,
+        catalog_name: &C,
+        schema_name: &S,
+        table_name: &T,
+        column_name: &N,
+    ) -> Return<()>
+    where
+        C: SqlStr + ?Sized,
+        S: SqlStr + ?Sized,
+        T: SqlStr + ?Sized,
+        N: SqlStr + ?Sized,
+    {
+        unsafe {
+            until_done(|| {
+                SQLColumnPrivileges(
+                    self.handle,
+                    catalog_name.as_text_ptr(),
+                    catalog_name.text_length(),
+                    schema_name.as_text_ptr(),
+                    schema_name.text_length(),
+                    table_name.as_text_ptr(),
+                    table_name.text_length(),
+                    column_name.as_text_ptr(),
+                    column_name.text_length(),
+                )
+            }).into()
+        }
+    }
+
+    pub fn primary_keys<C, S, T>(
+        &mut self,
+        catalog_name: &C,
+        schema_name: &S,
+        table_name: &T,
+    ) -> Return<()>
+    where
+        C: SqlStr + ?Sized,
+        S: SqlStr + ?Sized,
+        T: SqlStr + ?Sized,
+    {
+        unsafe {
+            until_done(|| {
+                SQLPrimaryKeys(
+                    self.handle,
+                    catalog_name.as_text_ptr(),
+                    catalog_name.text_length(),
+                    schema_name.as_text_ptr(),
+                    schema_name.text_length(),
+                    table_name.as_text_ptr(),
+                    table_name.text_length(),
+                )
+            }).into()
+        }
+    }
+
+    pub fn foreign_keys<PC, PS, PT, FC, FS, FT>(
+        &mut self,
+        pk_catalog_name: &PC,
+        pk_schema_name: &PS,
+        pk_table_name: &PT,
+        fk_catalog_name: &FC,
+        fk_schema_name: &FS,
+        fk_table_name: &FT,
+    ) -> Return<()>
+    where
+        PC: SqlStr + ?Sized,
+        PS: SqlStr + ?Sized,
+        PT: SqlStr + ?Sized,
+        FC: SqlStr + ?Sized,
+        FS: SqlStr + ?Sized,
+        FT: SqlStr + ?Sized,
+    {
+        unsafe {
+            until_done(|| {
+                SQLForeignKeys(
+                    self.handle,
+                    pk_catalog_name.as_text_ptr(),
+                    pk_catalog_name.text_length(),
+                    pk_schema_name.as_text_ptr(),
+                    pk_schema_name.text_length(),
+                    pk_table_name.as_text_ptr(),
+                    pk_table_name.text_length(),
+                    fk_catalog_name.as_text_ptr(),
+                    fk_catalog_name.text_length(),
+                    fk_schema_name.as_text_ptr(),
+                    fk_schema_name.text_length(),
+                    fk_table_name.as_text_ptr(),
+                    fk_table_name.text_length(),
+                )
+            }).into()
+        }
+    }
+
+    pub fn statistics<C, S, T>(
+        &mut self,
+        catalog_name: &C,
+        schema_name: &S,
+        table_name: &T,
+        unique: SQLUSMALLINT,
+        reserved: SQLUSMALLINT,
+    ) -> Return<()>
+    where
+        C: SqlStr + ?Sized,
+        S: SqlStr + ?Sized,
+        T: SqlStr + ?Sized,
+    {
+        unsafe {
+            until_done(|| {
+                SQLStatistics(
+                    self.handle,
+                    catalog_name.as_text_ptr(),
+                    catalog_name.text_length(),
+                    schema_name.as_text_ptr(),
+                    schema_name.text_length(),
+                    table_name.as_text_ptr(),
+                    table_name.text_length(),
+                    unique,
+                    reserved,
+                )
+            }).into()
+        }
+    }
+
+    pub fn special_columns<C, S, T>(
+        &mut self,
+        identifier_type: SQLUSMALLINT,
+        catalog_name: &C,
+        schema_name: &S,
+        table_name: &T,
+        scope: SQLUSMALLINT,
+        nullable: SQLUSMALLINT,
+    ) -> Return<()>
+    where
+        C: SqlStr + ?Sized,
+        S: SqlStr + ?Sized,
+        T: SqlStr + ?Sized,
+    {
+        unsafe {
+            until_done(|| {
+                SQLSpecialColumns(
+                    self.handle,
+                    identifier_type,
+                    catalog_name.as_text_ptr(),
+                    catalog_name.text_length(),
+                    schema_name.as_text_ptr(),
+                    schema_name.text_length(),
+                    table_name.as_text_ptr(),
+                    table_name.text_length(),
+                    scope,
+                    nullable,
+                )
+            }).into()
+        }
+    }
+
+    pub fn procedures<C, S, P>(
+        &mut self,
+        catalog_name: &C,
+        schema_name: &S,
+        proc_name: &P,
+    ) -> Return<()>
+    where
+        C: SqlStr + ?Sized,
+        S: SqlStr + ?Sized,
+        P: SqlStr + ?Sized,
+    {
+        unsafe {
+            until_done(|| {
+                SQLProcedures(
+                    self.handle,
+                    catalog_name.as_text_ptr(),
+                    catalog_name.text_length(),
+                    schema_name.as_text_ptr(),
+                    schema_name.text_length(),
+                    proc_name.as_text_ptr(),
+                    proc_name.text_length(),
+                )
+            }).into()
+        }
+    }
+
+    pub fn procedure_columns<C, S, P, N>(
+        &mut self,
+        catalog_name: &C,
+        schema_name: &S,
+        proc_name: &P,
+        column_name: &N,
+    ) -> Return<()>
+    where
+        C: SqlStr + ?Sized,
+        S: SqlStr + ?Sized,
+        P: SqlStr + ?Sized,
+        N: SqlStr + ?Sized,
+    {
+        unsafe {
+            until_done(|| {
+                SQLProcedureColumns(
+                    self.handle,
+                    catalog_name.as_text_ptr(),
+                    catalog_name.text_length(),
+                    schema_name.as_text_ptr(),
+                    schema_name.text_length(),
+                    proc_name.as_text_ptr(),
+                    proc_name.text_length(),
+                    column_name.as_text_ptr(),
+                    column_name.text_length(),
+                )
+            }).into()
+        }
+    }
+
+    pub fn type_info(&mut self, data_type: SqlDataType) -> Return<()> {
+        unsafe { until_done(|| SQLGetTypeInfo(self.handle, data_type)).into() }
+    }
+
     pub fn reset_parameters(&mut self) -> Return<()> {
-        unsafe { SQLFreeStmt(self.handle, SQL_RESET_PARAMS).into() }
+        unsafe {
+            // Explicit descriptors must no longer be referenced once the parameters are reset
+            let ret = self.set_attribute(SQL_ATTR_APP_PARAM_DESC, null_mut());
+            if let Error(()) = ret {
+                return ret;
+            }
+            SQLFreeStmt(self.handle, SQL_RESET_PARAMS).into()
+        }
     }
 
     pub fn execute(&mut self) -> ReturnOption<()> {
-        unsafe { SQLExecute(self.handle).into() }
+        let ret = unsafe { until_done(|| SQLExecute(self.handle)) };
+        self.reject_need_data(ret).into()
+    }
+
+    /// Same as `exec_direct`, but allows for parameters bound as data-at-execution
+    pub fn exec_direct_at_exec<T>(&mut self, statement_text: &T) -> ReturnNeedData<()>
+    where
+        T: SqlStr + ?Sized,
+    {
+        unsafe {
+            until_done(|| {
+                SQLExecDirect(
+                    self.handle,
+                    statement_text.as_text_ptr(),
+                    statement_text.text_length_int(),
+                )
+            }).into()
+        }
+    }
+
+    /// Same as `execute`, but allows for parameters bound as data-at-execution
+    pub fn execute_at_exec(&mut self) -> ReturnNeedData<()> {
+        unsafe { until_done(|| SQLExecute(self.handle)).into() }
+    }
+
+    /// Moves on to the next data-at-execution parameter. In case of `NeedData` the value pointer
+    /// the parameter has been bound with is returned. Otherwise the result of the execution.
+    pub fn param_data(&mut self) -> ReturnNeedData<(), SQLPOINTER> {
+        let mut value = null_mut();
+        let ret: ReturnNeedData<()> =
+            unsafe { until_done(|| SQLParamData(self.handle, &mut value)).into() };
+        match ret {
+            ReturnNeedData::Success(()) => ReturnNeedData::Success(()),
+            ReturnNeedData::Info(()) => ReturnNeedData::Info(()),
+            ReturnNeedData::NeedData(()) => ReturnNeedData::NeedData(value),
+            ReturnNeedData::NoData(()) => ReturnNeedData::NoData(()),
+            ReturnNeedData::Error(()) => ReturnNeedData::Error(()),
+        }
+    }
+
+    /// Sends a chunk of data for the current data-at-execution parameter
+    pub fn put_data(&mut self, data: &[u8]) -> Return<()> {
+        unsafe {
+            until_done(|| {
+                SQLPutData(
+                    self.handle,
+                    data.as_ptr() as SQLPOINTER,
+                    data.len() as SQLLEN,
+                )
+            }).into()
+        }
+    }
+
+    /// Sets the current data-at-execution parameter to NULL
+    pub fn put_null(&mut self) -> Return<()> {
+        unsafe { until_done(|| SQLPutData(self.handle, null_mut(), SQL_NULL_DATA)).into() }
+    }
+
+    /// Cancels the processing of the statement
+    pub fn cancel(&mut self) -> Return<()> {
+        unsafe { SQLCancel(self.handle).into() }
+    }
+
+    /// Returns a handle which can cancel the processing of the statement from another thread
+    pub fn cancel_handle(&mut self) -> CancelHandle<'env> {
+        let handle = self.handle;
+        let shared = self.shared.get_or_insert_with(|| SharedHandle::new(handle));
+        CancelHandle::new(shared.clone())
     }
 
     /// Release all columen buffers bound by `bind_col`. Except bookmark column.
     pub fn reset_columns(&mut self) -> Return<()> {
-        unsafe { SQLFreeStmt(self.handle, SQL_UNBIND).into() }
+        unsafe {
+            // Explicit descriptors must no longer be referenced once the columns are unbound
+            let ret = self.set_attribute(SQL_ATTR_APP_ROW_DESC, null_mut());
+            if let Error(()) = ret {
+                return ret;
+            }
+            SQLFreeStmt(self.handle, SQL_UNBIND).into()
+        }
     }
 
     /// Binds application data buffers to columns in the result set
@@ -188,16 +911,234 @@ impl<'env, 'param> HStmt<'env> {
             Some(indicator) => indicator,
             None => null_mut(),
         };
-        SQLBindCol(
-            self.handle,
+        self.bind_col_ptr(
             column_number,
             T::c_data_type(),
             value.mut_sql_ptr(),
             value.buffer_len(),
             indicator,
+        )
+    }
+
+    /// Binds a raw buffer to a column in the result set
+    ///
+    /// In case of a block cursor `buffer_length` is the length of a single element. It is the
+    /// callers responsibility to make sure the bound buffers are large enough and live long enough.
+    pub unsafe fn bind_col_ptr(
+        &mut self,
+        column_number: SQLUSMALLINT,
+        target_type: SqlCDataType,
+        value: SQLPOINTER,
+        buffer_length: SQLLEN,
+        indicator: *mut SQLLEN,
+    ) -> Return<()> {
+        SQLBindCol(
+            self.handle,
+            column_number,
+            target_type,
+            value,
+            buffer_length,
+            indicator,
         ).into()
     }
 
+    /// Sets an integer or pointer valued statement attribute
+    ///
+    /// It is the callers responsibility to make sure pointers passed as `value` stay valid as long
+    /// as the driver may access them.
+    pub unsafe fn set_attribute(&mut self, attribute: SQLINTEGER, value: SQLPOINTER) -> Return<()> {
+        SQLSetStmtAttr(self.handle, attribute, value, 0).into()
+    }
+
+    /// Enables or disables asynchronous execution of the functions of this statement
+    pub fn set_async_enable(&mut self, enabled: bool) -> Return<()> {
+        let value = if enabled { SQL_ASYNC_ENABLE_ON } else { SQL_ASYNC_ENABLE_OFF };
+        unsafe { self.set_attribute(SQL_ATTR_ASYNC_ENABLE, value as SQLPOINTER) }
+    }
+
+    /// Number of seconds to wait for an SQL statement to execute. `0` waits indefinitely.
+    pub fn set_query_timeout(&mut self, seconds: SQLUINTEGER) -> Return<()> {
+        unsafe { self.set_attribute(SQL_ATTR_QUERY_TIMEOUT, seconds as SQLPOINTER) }
+    }
+
+    /// Retrieves the descriptor currently associated with the statement. `attribute` is one of
+    /// `SQL_ATTR_APP_ROW_DESC`, `SQL_ATTR_APP_PARAM_DESC`, `SQL_ATTR_IMP_ROW_DESC` or
+    /// `SQL_ATTR_IMP_PARAM_DESC`.
+    pub fn descriptor(&self, attribute: SQLINTEGER) -> Return<SQLHDESC> {
+        let mut out: SQLHDESC = null_mut();
+        let value: *mut SQLHDESC = &mut out;
+        let ret: Return<()> = unsafe {
+            SQLGetStmtAttr(self.handle, attribute, value as SQLPOINTER, 0, null_mut()).into()
+        };
+        ret.map(|()| out)
+    }
+
+    /// Retrieves an integer or pointer valued statement attribute
+    pub fn attribute(&self, attribute: SQLINTEGER) -> Return<SQLULEN> {
+        let mut out: SQLULEN = 0;
+        let value: *mut SQLULEN = &mut out;
+        let ret: Return<()> = unsafe {
+            SQLGetStmtAttr(self.handle, attribute, value as SQLPOINTER, 0, null_mut()).into()
+        };
+        ret.map(|()| out)
+    }
+
+    /// Configures a block cursor fetching `row_status.len()` rows at once.
+    ///
+    /// `bind_type` is either `SQL_BIND_BY_COLUMN` or the size of a single row. It is the callers
+    /// responsibility to make sure `rows_fetched` and `row_status` live long enough. Should an
+    /// error occur, some of the attributes may already be set.
+    pub unsafe fn bind_row_set(
+        &mut self,
+        bind_type: SQLULEN,
+        rows_fetched: &mut SQLULEN,
+        row_status: &mut [SQLUSMALLINT],
+    ) -> Return<()> {
+        let rows_fetched: *mut SQLULEN = rows_fetched;
+        let attributes = [
+            (SQL_ATTR_ROW_BIND_TYPE, bind_type as SQLPOINTER),
+            (SQL_ATTR_ROW_STATUS_PTR, row_status.as_mut_ptr() as SQLPOINTER),
+            (SQL_ATTR_ROWS_FETCHED_PTR, rows_fetched as SQLPOINTER),
+            (SQL_ATTR_ROW_ARRAY_SIZE, row_status.len() as SQLPOINTER),
+        ];
+        let mut result = Success(());
+        for &(attribute, value) in &attributes {
+            match self.set_attribute(attribute, value) {
+                Success(()) => (),
+                Info(()) => result = Info(()),
+                Error(()) => return Error(()),
+            }
+        }
+        result
+    }
+
+    /// Fetches a single row at a time again and releases the buffers bound by `bind_row_set`.
+    pub fn reset_row_set(&mut self) -> Return<()> {
+        let attributes = [
+            (SQL_ATTR_ROW_ARRAY_SIZE, 1 as SQLPOINTER),
+            (SQL_ATTR_ROWS_FETCHED_PTR, null_mut()),
+            (SQL_ATTR_ROW_STATUS_PTR, null_mut()),
+            (SQL_ATTR_ROW_BIND_TYPE, SQL_BIND_BY_COLUMN as SQLPOINTER),
+        ];
+        let mut result = Success(());
+        for &(attribute, value) in &attributes {
+            match unsafe { self.set_attribute(attribute, value) } {
+                Success(()) => (),
+                Info(()) => result = Info(()),
+                Error(()) => return Error(()),
+            }
+        }
+        result
+    }
+
+    /// Number of rows fetched with each call to `fetch`
+    pub fn row_array_size(&self) -> Return<SQLULEN> {
+        self.attribute(SQL_ATTR_ROW_ARRAY_SIZE)
+    }
+
+    /// Either `SQL_BIND_BY_COLUMN` or the size of a single row for row wise binding
+    pub fn row_bind_type(&self) -> Return<SQLULEN> {
+        self.attribute(SQL_ATTR_ROW_BIND_TYPE)
+    }
+
+    /// Reads the number of rows fetched by the last call to `fetch`. `None` if no buffer has been
+    /// bound using `bind_row_set`.
+    ///
+    /// May only be called as long as the buffer bound with `bind_row_set` is valid.
+    pub unsafe fn rows_fetched(&self) -> Return<Option<SQLULEN>> {
+        self.attribute(SQL_ATTR_ROWS_FETCHED_PTR).map(|ptr| {
+            let ptr = ptr as *const SQLULEN;
+            if ptr.is_null() { None } else { Some(*ptr) }
+        })
+    }
+
+    /// Reads the status of a row in the current row set. `None` if no buffer has been bound using
+    /// `bind_row_set`.
+    ///
+    /// May only be called as long as the buffer bound with `bind_row_set` is valid and
+    /// `row_index` is smaller than the row array size.
+    pub unsafe fn row_status(&self, row_index: usize) -> Return<Option<SQLUSMALLINT>> {
+        self.attribute(SQL_ATTR_ROW_STATUS_PTR).map(|ptr| {
+            let ptr = ptr as *const SQLUSMALLINT;
+            if ptr.is_null() { None } else { Some(*ptr.add(row_index)) }
+        })
+    }
+
+    pub fn num_params(&self) -> Return<SQLSMALLINT> {
+        let mut out: SQLSMALLINT = 0;
+        let ret: Return<()> = unsafe { until_done(|| SQLNumParams(self.handle, &mut out)).into() };
+        ret.map(|()| out)
+    }
+
+    pub fn describe_param(
+        &mut self,
+        parameter_number: SQLUSMALLINT,
+        data_type: &mut SQLSMALLINT,
+        parameter_size: &mut SQLULEN,
+        decimal_digits: &mut SQLSMALLINT,
+        nullable: &mut SQLSMALLINT,
+    ) -> Return<()> {
+        unsafe {
+            until_done(|| {
+                SQLDescribeParam(
+                    self.handle,
+                    parameter_number,
+                    data_type,
+                    parameter_size,
+                    decimal_digits,
+                    nullable,
+                )
+            }).into()
+        }
+    }
+
+    pub fn numeric_col_attribute(
+        &mut self,
+        column_number: SQLUSMALLINT,
+        field_identifier: SQLUSMALLINT,
+    ) -> Return<SQLLEN> {
+        let mut out: SQLLEN = 0;
+        let ret: Return<()> = unsafe {
+            until_done(|| {
+                SQLColAttribute(
+                    self.handle,
+                    column_number,
+                    field_identifier,
+                    null_mut(),
+                    0,
+                    null_mut(),
+                    &mut out,
+                )
+            }).into()
+        };
+        ret.map(|()| out)
+    }
+
+    pub fn string_col_attribute<T>(
+        &mut self,
+        column_number: SQLUSMALLINT,
+        field_identifier: SQLUSMALLINT,
+        value: &mut T,
+        indicator: &mut SQLSMALLINT,
+    ) -> Return<()>
+    where
+        T: OutputBuffer + ?Sized,
+    {
+        unsafe {
+            until_done(|| {
+                SQLColAttribute(
+                    self.handle,
+                    column_number,
+                    field_identifier,
+                    value.mut_buf_ptr() as SQLPOINTER,
+                    value.buf_len(),
+                    indicator,
+                    null_mut(),
+                )
+            }).into()
+        }
+    }
+
     pub fn describe_col<T>(
         &mut self,
         column_number: SQLUSMALLINT,
@@ -212,17 +1153,19 @@ impl<'env, 'param> HStmt<'env> {
         T: OutputBuffer + ?Sized,
     {
         unsafe {
-            SQLDescribeCol(
-                self.handle,
-                column_number,
-                column_name.mut_buf_ptr(),
-                column_name.buf_len(),
-                column_name_indicator,
-                data_type,
-                column_size,
-                decimal_digits,
-                nullable,
-            ).into()
+            until_done(|| {
+                SQLDescribeCol(
+                    self.handle,
+                    column_number,
+                    column_name.mut_buf_ptr(),
+                    column_name.buf_len(),
+                    column_name_indicator,
+                    data_type,
+                    column_size,
+                    decimal_digits,
+                    nullable,
+                )
+            }).into()
         }
     }
 }
@@ -13,13 +13,17 @@
 //! those instances will live just as long as the actual datastructures managed by ODBC.
 
 pub use self::hdbc::HDbc;
+pub use self::hdesc::HDesc;
 pub use self::henv::HEnv;
 pub use self::hstmt::HStmt;
-use super::{CDataType, DataType, Indicator, OutputBuffer, Return, ReturnOption, SqlStr};
-use sys::{HandleType, SQLHANDLE};
+use super::{Backoff, CDataType, DataType, Indicator, OutputBuffer, Return, ReturnAsync,
+            ReturnNeedData, ReturnOption, SqlStr, Success, Info, Error};
+use sys::{HandleType, SQLHANDLE, SQLRETURN, SQL_STILL_EXECUTING};
+use std::thread::sleep;
 
 mod henv;
 mod hdbc;
+mod hdesc;
 mod hstmt;
 
 /// Basic functionality for all wrappers around ODBC Handles
@@ -29,3 +33,23 @@ pub unsafe trait Handle {
     /// Returns a ptr to the wrapped ODBC Object
     fn handle(&self) -> SQLHANDLE;
 }
+
+/// Calls `f` until it no longer returns `SQL_STILL_EXECUTING`. Functions of the handles not
+/// explicitly meant for polling block until they are complete, even if asynchronous execution is
+/// enabled. The thread sleeps between two calls, rather than spinning.
+fn until_done<F>(mut f: F) -> SQLRETURN
+where
+    F: FnMut() -> SQLRETURN,
+{
+    let backoff = Backoff::default();
+    let mut delay = backoff.initial;
+    loop {
+        match f() {
+            SQL_STILL_EXECUTING => {
+                sleep(delay);
+                delay = backoff.next(delay);
+            }
+            other => return other,
+        }
+    }
+}
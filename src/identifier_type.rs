@@ -0,0 +1,24 @@
+use sys::*;
+
+/// Kind of columns reported by `Statement::special_columns`.
+///
+/// See [SQLSpecialColumns Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlspecialcolumns-function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentifierType {
+    /// The optimal set of columns uniquely identifying a row in the table.
+    BestRowId,
+    /// The columns which are automatically updated by the data source, whenever any value in the
+    /// row is updated.
+    RowVersion,
+}
+
+impl IdentifierType {
+    /// Value passed to the driver as `IdentifierType` argument of `SQLSpecialColumns`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        match *self {
+            IdentifierType::BestRowId => 1,
+            IdentifierType::RowVersion => 2,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use sys::*;
+
+/// Indexes reported by `Statement::statistics`.
+///
+/// See [SQLStatistics Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlstatistics-function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexType {
+    /// Only unique indexes are returned.
+    Unique,
+    /// All indexes are returned.
+    All,
+}
+
+impl IndexType {
+    /// Value passed to the driver as `Unique` argument of `SQLStatistics`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        match *self {
+            IndexType::Unique => 0,
+            IndexType::All => 1,
+        }
+    }
+}
@@ -0,0 +1,405 @@
+use super::*;
+use sys::*;
+
+/// Information about the driver and data source, which can be retrieved using `Connection::info`.
+///
+/// Implemented by `StringInfo`, `YesNoInfo`, `U16Info` and `U32Info`, as well as by the unit
+/// structs for information decoded into a dedicated type, e.g. `TxnIsolationOption`. `Value` is
+/// the type of the information returned by the driver.
+///
+/// See [SQLGetInfo Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlgetinfo-function
+pub trait ConnectionInfo: Copy + private::Get {}
+
+mod private {
+    use super::*;
+
+    /// Reads the information from the driver. Not part of the public interface, so `HDbc` stays
+    /// an implementation detail.
+    pub trait Get {
+        type Value;
+
+        fn get(self, handle: &HDbc) -> Return<Self::Value>;
+    }
+}
+
+/// Information describing the driver or data source as a character string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringInfo {
+    /// Character used to separate a catalog name from the following name element, e.g. `.`
+    CatalogNameSeparator,
+    /// Name the data source vendor uses for a catalog, e.g. `database`.
+    CatalogTerm,
+    /// Name of the default collation of the default character set.
+    CollationSeq,
+    /// Data source name used during connection.
+    DataSourceName,
+    /// Name of the DBMS product.
+    DbmsName,
+    /// Version of the DBMS product in the form `##.##.####`, optionally followed by a product
+    /// specific version.
+    DbmsVer,
+    /// File name of the driver.
+    DriverName,
+    /// Version of ODBC supported by the driver in the form `##.##`.
+    DriverOdbcVer,
+    /// Version of the driver in the form `##.##.####`, optionally followed by a description.
+    DriverVer,
+    /// Character used to quote identifiers. A blank if quoting identifiers is not supported.
+    IdentifierQuoteChar,
+    /// Comma separated list of data source specific keywords, which are not ODBC keywords.
+    Keywords,
+    /// Name the data source vendor uses for a procedure, e.g. `stored procedure`.
+    ProcedureTerm,
+    /// Name the data source vendor uses for a schema, e.g. `owner`.
+    SchemaTerm,
+    /// Character used to escape the wildcards `_` and `%` in search patterns.
+    SearchPatternEscape,
+    /// Data source specific server name.
+    ServerName,
+    /// Special characters which may be used in identifiers, besides `a..z`, `A..Z`, `0..9` and
+    /// `_`.
+    SpecialCharacters,
+    /// Name the data source vendor uses for a table.
+    TableTerm,
+    /// Name used in a particular database, which may differ from the login name.
+    UserName,
+}
+
+/// Information describing the driver or data source as `Y` or `N`, which is returned as `bool`.
+/// Other answers are reported as an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YesNoInfo {
+    /// Whether the procedures returned by `Statement::procedures` can be executed by the user.
+    AccessibleProcedures,
+    /// Whether `SELECT` is granted on all tables returned by `Statement::tables`.
+    AccessibleTables,
+    /// Whether the data source supports catalog names.
+    CatalogName,
+    /// Whether the data source supports column aliases.
+    ColumnAlias,
+    /// Whether the data source is set to READ ONLY mode.
+    DataSourceReadOnly,
+    /// Whether parameters can be described using `Statement::describe_param`.
+    DescribeParameter,
+    /// Whether the data source supports the Integrity Enhancement Facility.
+    Integrity,
+    /// Whether the escape character is supported for `%` and `_` in `LIKE` predicates.
+    LikeEscapeClause,
+    /// Whether the data source supports multiple result sets.
+    MultipleResultSets,
+    /// Whether the length of long data values must be sent before sending them at execution.
+    NeedLongDataLen,
+    /// Whether columns in the `ORDER BY` clause must be in the select list.
+    OrderByColumnsInSelect,
+}
+
+/// Information describing the driver or data source as a 16 bit integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum U16Info {
+    /// How a commit affects cursors and prepared statements. `0` deletes, `1` closes cursors,
+    /// `2` preserves both.
+    CursorCommitBehavior,
+    /// How a rollback affects cursors and prepared statements. Same values as
+    /// `CursorCommitBehavior`.
+    CursorRollbackBehavior,
+    /// Case of identifiers. `1` upper, `2` lower, `3` case sensitive, `4` mixed case.
+    IdentifierCase,
+    /// Maximum number of active statements. `0` if there is no limit.
+    MaxConcurrentActivities,
+    /// Maximum length of a catalog name. `0` if there is no limit.
+    MaxCatalogNameLen,
+    /// Maximum length of a column name. `0` if there is no limit.
+    MaxColumnNameLen,
+    /// Maximum number of columns in a select list. `0` if there is no limit.
+    MaxColumnsInSelect,
+    /// Maximum number of columns in a table. `0` if there is no limit.
+    MaxColumnsInTable,
+    /// Maximum length of a cursor name. `0` if there is no limit.
+    MaxCursorNameLen,
+    /// Maximum number of active connections. `0` if there is no limit.
+    MaxDriverConnections,
+    /// Maximum length of an identifier.
+    MaxIdentifierLen,
+    /// Maximum length of a schema name. `0` if there is no limit.
+    MaxSchemaNameLen,
+    /// Maximum length of a table name. `0` if there is no limit.
+    MaxTableNameLen,
+    /// Maximum number of tables in the `FROM` clause. `0` if there is no limit.
+    MaxTablesInSelect,
+    /// Maximum length of a user name. `0` if there is no limit.
+    MaxUserNameLen,
+    /// Where NULL values are sorted. `0` high, `1` low, `2` at the start, `4` at the end.
+    NullCollation,
+}
+
+/// Information describing the driver or data source as a 32 bit integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum U32Info {
+    /// Level of asynchronous execution supported by the driver. `0` none, `1` connection level,
+    /// in which all statements of a connection execute asynchronously or none does, `2`
+    /// statement level.
+    AsyncMode,
+    /// Whether cursors may detect changes made by other cursors. Same values as
+    /// `CursorSensitivity`.
+    CursorSensitivity,
+    /// Maximum number of statements executing asynchronously at the same time. `0` if there is no
+    /// limit.
+    MaxAsyncConcurrentStatements,
+    /// Maximum length of a row. `0` if there is no limit.
+    MaxRowSize,
+    /// Maximum length of an SQL statement. `0` if there is no limit.
+    MaxStatementLen,
+    /// Level of ODBC interface conformance. `1` core, `2` level 1, `3` level 2.
+    OdbcInterfaceConformance,
+}
+
+/// Level of transaction support as `TransactionSupport`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TxnCapable;
+
+/// Transaction isolation levels supported by the data source as `IsolationLevels`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TxnIsolationOption;
+
+/// Default transaction isolation level of the data source as `IsolationLevel`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DefaultTxnIsolation;
+
+/// Level of SQL-92 conformance as `Sql92Conformance`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SqlConformance;
+
+/// Cursor types supported by the data source as `CursorTypes`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollOptions;
+
+/// Extensions to the restrictions of `Statement::get_data` as `GetDataSupport`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetdataExtensions;
+
+impl StringInfo {
+    /// Value passed to the driver as `InfoType` argument of `SQLGetInfo`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        use StringInfo::*;
+        match *self {
+            DataSourceName => 2,
+            DriverName => 6,
+            DriverVer => 7,
+            ServerName => 13,
+            SearchPatternEscape => 14,
+            DbmsName => 17,
+            DbmsVer => 18,
+            IdentifierQuoteChar => 29,
+            SchemaTerm => 39,
+            ProcedureTerm => 40,
+            CatalogNameSeparator => 41,
+            CatalogTerm => 42,
+            TableTerm => 45,
+            UserName => 47,
+            DriverOdbcVer => 77,
+            Keywords => 89,
+            SpecialCharacters => 94,
+            CollationSeq => 10004,
+        }
+    }
+}
+
+impl YesNoInfo {
+    /// Value passed to the driver as `InfoType` argument of `SQLGetInfo`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        use YesNoInfo::*;
+        match *self {
+            AccessibleTables => 19,
+            AccessibleProcedures => 20,
+            DataSourceReadOnly => 25,
+            MultipleResultSets => 36,
+            Integrity => 73,
+            ColumnAlias => 87,
+            OrderByColumnsInSelect => 90,
+            NeedLongDataLen => 111,
+            LikeEscapeClause => 113,
+            DescribeParameter => 10002,
+            CatalogName => 10003,
+        }
+    }
+}
+
+impl U16Info {
+    /// Value passed to the driver as `InfoType` argument of `SQLGetInfo`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        use U16Info::*;
+        match *self {
+            MaxDriverConnections => 0,
+            MaxConcurrentActivities => 1,
+            CursorCommitBehavior => 23,
+            CursorRollbackBehavior => 24,
+            IdentifierCase => 28,
+            MaxColumnNameLen => 30,
+            MaxCursorNameLen => 31,
+            MaxSchemaNameLen => 32,
+            MaxCatalogNameLen => 34,
+            MaxTableNameLen => 35,
+            NullCollation => 85,
+            MaxColumnsInSelect => 100,
+            MaxColumnsInTable => 101,
+            MaxTablesInSelect => 106,
+            MaxUserNameLen => 107,
+            MaxIdentifierLen => 10005,
+        }
+    }
+}
+
+impl U32Info {
+    /// Value passed to the driver as `InfoType` argument of `SQLGetInfo`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        use U32Info::*;
+        match *self {
+            MaxRowSize => 104,
+            MaxStatementLen => 105,
+            OdbcInterfaceConformance => 152,
+            CursorSensitivity => 10001,
+            AsyncMode => 10021,
+            MaxAsyncConcurrentStatements => 10022,
+        }
+    }
+}
+
+impl TxnCapable {
+    /// Value passed to the driver as `InfoType` argument of `SQLGetInfo`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        46
+    }
+}
+
+impl TxnIsolationOption {
+    /// Value passed to the driver as `InfoType` argument of `SQLGetInfo`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        72
+    }
+}
+
+impl DefaultTxnIsolation {
+    /// Value passed to the driver as `InfoType` argument of `SQLGetInfo`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        26
+    }
+}
+
+impl SqlConformance {
+    /// Value passed to the driver as `InfoType` argument of `SQLGetInfo`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        118
+    }
+}
+
+impl ScrollOptions {
+    /// Value passed to the driver as `InfoType` argument of `SQLGetInfo`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        44
+    }
+}
+
+impl GetdataExtensions {
+    /// Value passed to the driver as `InfoType` argument of `SQLGetInfo`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        81
+    }
+}
+
+impl ConnectionInfo for StringInfo {}
+impl ConnectionInfo for YesNoInfo {}
+impl ConnectionInfo for U16Info {}
+impl ConnectionInfo for U32Info {}
+impl ConnectionInfo for TxnCapable {}
+impl ConnectionInfo for TxnIsolationOption {}
+impl ConnectionInfo for DefaultTxnIsolation {}
+impl ConnectionInfo for SqlConformance {}
+impl ConnectionInfo for ScrollOptions {}
+impl ConnectionInfo for GetdataExtensions {}
+
+impl private::Get for StringInfo {
+    type Value = String;
+
+    fn get(self, handle: &HDbc) -> Return<String> {
+        handle.string_info(self.value())
+    }
+}
+
+impl private::Get for YesNoInfo {
+    type Value = bool;
+
+    fn get(self, handle: &HDbc) -> Return<bool> {
+        match handle.string_info(self.value()) {
+            Success(ref answer) if answer == "Y" => Success(true),
+            Success(ref answer) if answer == "N" => Success(false),
+            Info(ref answer) if answer == "Y" => Info(true),
+            Info(ref answer) if answer == "N" => Info(false),
+            _ => Error(()),
+        }
+    }
+}
+
+impl private::Get for U16Info {
+    type Value = SQLUSMALLINT;
+
+    fn get(self, handle: &HDbc) -> Return<SQLUSMALLINT> {
+        handle.info(self.value())
+    }
+}
+
+impl private::Get for U32Info {
+    type Value = SQLUINTEGER;
+
+    fn get(self, handle: &HDbc) -> Return<SQLUINTEGER> {
+        handle.info(self.value())
+    }
+}
+
+impl private::Get for TxnCapable {
+    type Value = TransactionSupport;
+
+    fn get(self, handle: &HDbc) -> Return<TransactionSupport> {
+        handle.info(self.value()).map(TransactionSupport::from_value)
+    }
+}
+
+impl private::Get for TxnIsolationOption {
+    type Value = IsolationLevels;
+
+    fn get(self, handle: &HDbc) -> Return<IsolationLevels> {
+        handle.info(self.value()).map(IsolationLevels::from_value)
+    }
+}
+
+impl private::Get for DefaultTxnIsolation {
+    type Value = IsolationLevel;
+
+    fn get(self, handle: &HDbc) -> Return<IsolationLevel> {
+        handle.info(self.value()).map(IsolationLevel::from_value)
+    }
+}
+
+impl private::Get for SqlConformance {
+    type Value = Sql92Conformance;
+
+    fn get(self, handle: &HDbc) -> Return<Sql92Conformance> {
+        handle.info(self.value()).map(Sql92Conformance::from_value)
+    }
+}
+
+impl private::Get for ScrollOptions {
+    type Value = CursorTypes;
+
+    fn get(self, handle: &HDbc) -> Return<CursorTypes> {
+        handle.info(self.value()).map(CursorTypes::from_value)
+    }
+}
+
+impl private::Get for GetdataExtensions {
+    type Value = GetDataSupport;
+
+    fn get(self, handle: &HDbc) -> Return<GetDataSupport> {
+        handle.info(self.value()).map(GetDataSupport::from_value)
+    }
+}
@@ -0,0 +1,150 @@
+use super::*;
+use sys::*;
+
+/// Level of transaction support reported for `TxnCapable`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionSupport {
+    /// Transactions are not supported.
+    None,
+    /// Transactions can only contain DML statements. DDL statements cause an error.
+    Dml,
+    /// Transactions can contain DML and DDL statements.
+    All,
+    /// Transactions can only contain DML statements. DDL statements commit the transaction.
+    DdlCommit,
+    /// Transactions can only contain DML statements. DDL statements are ignored.
+    DdlIgnore,
+    /// Value not defined by ODBC.
+    Other(SQLUSMALLINT),
+}
+
+impl TransactionSupport {
+    /// Decodes the value reported by the driver
+    pub(crate) fn from_value(value: SQLUSMALLINT) -> Self {
+        match value {
+            0 => TransactionSupport::None,
+            1 => TransactionSupport::Dml,
+            2 => TransactionSupport::All,
+            3 => TransactionSupport::DdlCommit,
+            4 => TransactionSupport::DdlIgnore,
+            other => TransactionSupport::Other(other),
+        }
+    }
+}
+
+/// Set of transaction isolation levels supported by the data source, reported for
+/// `TxnIsolationOption`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IsolationLevels {
+    bits: SQLUINTEGER,
+}
+
+impl IsolationLevels {
+    /// Decodes the value reported by the driver
+    pub(crate) fn from_value(bits: SQLUINTEGER) -> Self {
+        IsolationLevels { bits }
+    }
+
+    /// `true` if the data source supports `level`. Driver specific levels are never reported as
+    /// supported.
+    pub fn contains(&self, level: IsolationLevel) -> bool {
+        level.is_standard() && self.bits & level.value() != 0
+    }
+}
+
+/// Level of SQL-92 conformance reported for `SqlConformance`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sql92Conformance {
+    /// Entry level
+    Entry,
+    /// FIPS 127-2 transitional level
+    FipsTransitional,
+    /// Intermediate level
+    Intermediate,
+    /// Full level
+    Full,
+    /// Value not defined by ODBC.
+    Other(SQLUINTEGER),
+}
+
+impl Sql92Conformance {
+    /// Decodes the value reported by the driver
+    pub(crate) fn from_value(value: SQLUINTEGER) -> Self {
+        match value {
+            1 => Sql92Conformance::Entry,
+            2 => Sql92Conformance::FipsTransitional,
+            4 => Sql92Conformance::Intermediate,
+            8 => Sql92Conformance::Full,
+            other => Sql92Conformance::Other(other),
+        }
+    }
+}
+
+/// Set of cursor types supported by the data source, reported for `ScrollOptions`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CursorTypes {
+    bits: SQLUINTEGER,
+}
+
+impl CursorTypes {
+    /// Decodes the value reported by the driver
+    pub(crate) fn from_value(bits: SQLUINTEGER) -> Self {
+        CursorTypes { bits }
+    }
+
+    /// `true` if forward only cursors are supported.
+    pub fn forward_only(&self) -> bool {
+        self.bits & 1 != 0
+    }
+
+    /// `true` if mixed cursors, which are keyset-driven within the keyset and dynamic outside of
+    /// it, are supported.
+    pub fn mixed(&self) -> bool {
+        self.bits & 8 != 0
+    }
+
+    /// `true` if scrollable cursors of type `cursor_type` are supported.
+    pub fn contains(&self, cursor_type: CursorType) -> bool {
+        let bit = match cursor_type {
+            CursorType::KeysetDriven => 2,
+            CursorType::Dynamic => 4,
+            CursorType::Static => 16,
+        };
+        self.bits & bit != 0
+    }
+}
+
+/// Extensions to the restrictions of `Statement::get_data`, reported for `GetdataExtensions`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetDataSupport {
+    bits: SQLUINTEGER,
+}
+
+impl GetDataSupport {
+    /// Decodes the value reported by the driver
+    pub(crate) fn from_value(bits: SQLUINTEGER) -> Self {
+        GetDataSupport { bits }
+    }
+
+    /// `true` if `get_data` can be called for any unbound column, including those before the
+    /// last bound column.
+    pub fn any_column(&self) -> bool {
+        self.bits & 1 != 0
+    }
+
+    /// `true` if `get_data` can be called for unbound columns in any order.
+    pub fn any_order(&self) -> bool {
+        self.bits & 2 != 0
+    }
+
+    /// `true` if `get_data` can be called for a row of a block cursor, after positioning the
+    /// cursor on it using `set_pos`.
+    pub fn block(&self) -> bool {
+        self.bits & 4 != 0
+    }
+
+    /// `true` if `get_data` can be called for bound columns, too.
+    pub fn bound(&self) -> bool {
+        self.bits & 8 != 0
+    }
+}
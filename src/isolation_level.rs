@@ -0,0 +1,62 @@
+use super::*;
+use sys::*;
+
+/// Transaction isolation level of a connection, which can be set using
+/// `Connection::set_isolation_level`.
+///
+/// See [Setting the Transaction Isolation Level][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/setting-the-transaction-isolation-level
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsolationLevel {
+    /// Dirty reads, nonrepeatable reads and phantoms are possible.
+    ReadUncommitted,
+    /// Nonrepeatable reads and phantoms are possible, dirty reads are not.
+    ReadCommitted,
+    /// Phantoms are possible, dirty reads and nonrepeatable reads are not.
+    RepeatableRead,
+    /// Transactions are serializable.
+    Serializable,
+    /// Driver specific isolation level, e.g. snapshot isolation.
+    Other(SQLUINTEGER),
+}
+
+/// Reason `Connection::set_isolation_level` failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsolationLevelError {
+    /// The data source does not support the requested level. The isolation level of the
+    /// connection is unchanged. Holds the levels supported by the data source.
+    Unsupported(IsolationLevels),
+    /// The driver returned an error. Use diagnostics to obtain more information.
+    Driver,
+}
+
+impl IsolationLevel {
+    /// Value passed to the driver for `SQL_ATTR_TXN_ISOLATION`. Also the bit representing the
+    /// level in the bitmask reported for `TxnIsolationOption`.
+    pub(crate) fn value(&self) -> SQLUINTEGER {
+        match *self {
+            IsolationLevel::ReadUncommitted => 1,
+            IsolationLevel::ReadCommitted => 2,
+            IsolationLevel::RepeatableRead => 4,
+            IsolationLevel::Serializable => 8,
+            IsolationLevel::Other(value) => value,
+        }
+    }
+
+    /// Isolation level reported by the driver for `SQL_ATTR_TXN_ISOLATION`
+    pub(crate) fn from_value(value: SQLUINTEGER) -> Self {
+        match value {
+            1 => IsolationLevel::ReadUncommitted,
+            2 => IsolationLevel::ReadCommitted,
+            4 => IsolationLevel::RepeatableRead,
+            8 => IsolationLevel::Serializable,
+            other => IsolationLevel::Other(other),
+        }
+    }
+
+    /// `true` for the isolation levels defined by ODBC, which are covered by
+    /// `TxnIsolationOption`.
+    pub(crate) fn is_standard(&self) -> bool {
+        !matches!(*self, IsolationLevel::Other(_))
+    }
+}
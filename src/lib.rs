@@ -15,27 +15,64 @@
 
 pub extern crate odbc_sys as sys;
 
+pub use accuracy::Accuracy;
+pub use backoff::Backoff;
+pub use bind_type::BindType;
+pub use bulk_operation::BulkOperation;
 pub use c_data_type::CDataType;
-pub use data_source::{HDbcWrapper, DataSource, Unconnected, Connected, AutocommitOff, AutocommitOn, AutocommitMode};
+pub use cancel_handle::CancelHandle;
+pub use col_attribute::{NumericColAttribute, StringColAttribute};
+pub use concurrency::Concurrency;
+pub use connection_pooling::{ConnectionPooling, CpMatch};
+pub use cursor_sensitivity::CursorSensitivity;
+pub use cursor_type::CursorType;
+pub use data_source::{HDbcWrapper, DataSource, Unconnected, Connected, AutocommitOff, AutocommitOn, AutocommitMode,
+                      ConnectFuture, Transaction};
 pub use data_type::DataType;
-pub use diagnostics::{Diagnostics, DiagResult};
+pub use desc_field::DescField;
+pub use descriptor::{Descriptor, Explicit, Implicit};
+pub use diagnostics::{Diagnostics, DiagResult, ErrorKind};
 pub use environment::Environment;
+pub use function_id::FunctionId;
 
 pub use handles::Handle;
+pub use identifier_type::IdentifierType;
+pub use index_type::IndexType;
 pub use indicator::Indicator;
+pub use isolation_level::{IsolationLevel, IsolationLevelError};
+pub use info::{ConnectionInfo, StringInfo, YesNoInfo, U16Info, U32Info, TxnCapable,
+               TxnIsolationOption, DefaultTxnIsolation, SqlConformance, ScrollOptions,
+               GetdataExtensions};
+pub use info_values::{TransactionSupport, IsolationLevels, Sql92Conformance, CursorTypes,
+                      GetDataSupport};
+pub use lock_type::LockType;
+pub use nullable_columns::NullableColumns;
+pub use param_status::ParamStatus;
+pub use procedure_column_type::ProcedureColumnType;
 pub use return_::{Return, Success, Info, Error};
 pub use return_option::ReturnOption;
+pub use return_need_data::ReturnNeedData;
+pub use return_async::ReturnAsync;
+pub use row_id_scope::RowIdScope;
+pub use row_status::RowStatus;
+pub use set_pos_operation::SetPosOperation;
 pub use sql_str::SqlStr;
-pub use statement::{Statement, NoCursor, Open, Positioned, Unprepared, Prepared, CursorState};
+pub use supported_functions::SupportedFunctions;
+pub use type_info::{TypeInfo, Searchable};
+pub use statement::{Statement, NoCursor, Open, Positioned, Unprepared, Prepared, CursorState,
+                    NeedData, Executing, ParamWriter, DataReader, ForwardOnly, Scrollable,
+                    StatementFuture, DescribesColumns};
 pub use version::{NoVersion, Odbc3, Odbc3m8};
 pub use version::Version;
 
 use output_buffer::OutputBuffer;
-use handles::{HEnv, HDbc, HStmt};
+use handles::{HEnv, HDbc, HDesc, HStmt};
 
 mod version;
 mod return_;
 mod return_option;
+mod return_need_data;
+mod return_async;
 mod sql_str;
 mod handles;
 mod diagnostics;
@@ -46,9 +83,40 @@ mod c_data_type;
 mod indicator;
 mod data_type;
 mod output_buffer;
+mod ffi;
+mod bind_type;
+mod row_status;
+mod param_status;
+mod cursor_type;
+mod cursor_sensitivity;
+mod set_pos_operation;
+mod bulk_operation;
+mod lock_type;
+mod concurrency;
+mod index_type;
+mod accuracy;
+mod identifier_type;
+mod row_id_scope;
+mod nullable_columns;
+mod procedure_column_type;
+mod type_info;
+mod col_attribute;
+mod cancel_handle;
+mod timeout;
+mod backoff;
+mod timer;
+mod desc_field;
+mod descriptor;
+mod info;
+mod info_values;
+mod function_id;
+mod supported_functions;
+mod isolation_level;
+mod connection_pooling;
 
 /// `Connection` can be used as a shorthand for a `DataSource` in `Connected` state.
 pub type Connection<'env, AC> = DataSource<'env, Connected<'env, AC>>;
 
 /// Shorthand for `Statements` in `Open` state.
-pub type ResultSet<'con, 'param, 'col, P> = Statement<'con, 'param, 'col, Open, P>;
+pub type ResultSet<'con, 'param, 'col, P, CT = ForwardOnly> =
+    Statement<'con, 'param, 'col, Open, P, CT>;
@@ -0,0 +1,26 @@
+use sys::*;
+
+/// Lock placed on rows by `Statement::set_pos`.
+///
+/// See [SQLSetPos Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlsetpos-function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockType {
+    /// The lock state of the row is left unchanged.
+    NoChange,
+    /// Locks the row exclusively.
+    Exclusive,
+    /// Unlocks the row.
+    Unlock,
+}
+
+impl LockType {
+    /// Value passed to the driver as `LockType` argument of `SQLSetPos`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        match *self {
+            LockType::NoChange => 0,
+            LockType::Exclusive => 1,
+            LockType::Unlock => 2,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use sys::*;
+
+/// Whether `Statement::special_columns` reports columns, which may be NULL.
+///
+/// See [SQLSpecialColumns Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlspecialcolumns-function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NullableColumns {
+    /// Only columns which can not be NULL are reported.
+    Exclude,
+    /// Columns which may be NULL are reported, too.
+    Include,
+}
+
+impl NullableColumns {
+    /// Value passed to the driver as `Nullable` argument of `SQLSpecialColumns`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        match *self {
+            NullableColumns::Exclude => 0,
+            NullableColumns::Include => 1,
+        }
+    }
+}
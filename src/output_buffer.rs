@@ -43,6 +43,16 @@ impl BufferLength for SQLSMALLINT {
     }
 }
 
+impl BufferLength for SQLINTEGER {
+    fn max_value() -> usize {
+        SQLINTEGER::MAX as usize
+    }
+
+    fn from_usize(len: usize) -> Self {
+        len as Self
+    }
+}
+
 impl BufferLength for SQLLEN {
     fn max_value() -> usize {
         Self::max_value() as usize
@@ -0,0 +1,37 @@
+use sys::*;
+
+/// Status of a single set of parameters after executing a statement with parameter arrays.
+///
+/// See [SQLExecute Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlexecute-function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamStatus {
+    /// The SQL statement was successfully executed for this set of parameters.
+    Success,
+    /// The SQL statement was successfully executed for this set of parameters; however, warning
+    /// information is available in the diagnostics data structure.
+    SuccessWithInfo,
+    /// An error occurred in processing this set of parameters.
+    Error,
+    /// This set of parameters was not used, possibly because a previous set of parameters caused
+    /// an error that aborted further processing.
+    Unused,
+    /// The driver treats arrays of parameters as a monolithic unit and so does not generate this
+    /// level of error information.
+    DiagUnavailable,
+    /// A status not defined by ODBC, returned by the driver.
+    Other(SQLUSMALLINT),
+}
+
+impl From<SQLUSMALLINT> for ParamStatus {
+    fn from(source: SQLUSMALLINT) -> ParamStatus {
+        match source {
+            0 => ParamStatus::Success,
+            1 => ParamStatus::DiagUnavailable,
+            5 => ParamStatus::Error,
+            6 => ParamStatus::SuccessWithInfo,
+            7 => ParamStatus::Unused,
+            other => ParamStatus::Other(other),
+        }
+    }
+}
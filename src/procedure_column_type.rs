@@ -0,0 +1,38 @@
+use sys::*;
+
+/// Kind of a column returned by `Statement::procedure_columns`, as found in its `COLUMN_TYPE`
+/// field.
+///
+/// See [SQLProcedureColumns Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlprocedurecolumns-function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcedureColumnType {
+    /// The procedure column is an input parameter.
+    Input,
+    /// The procedure column is an output parameter.
+    Output,
+    /// The procedure column is an input/output parameter.
+    InputOutput,
+    /// The procedure column is the return value of the procedure.
+    ReturnValue,
+    /// The procedure column is a column of a result set returned by the procedure.
+    ResultColumn,
+    /// The procedure column is a parameter whose type is unknown.
+    Unknown,
+    /// A column type not defined by ODBC, returned by the driver.
+    Other(SQLSMALLINT),
+}
+
+impl From<SQLSMALLINT> for ProcedureColumnType {
+    fn from(source: SQLSMALLINT) -> ProcedureColumnType {
+        match source {
+            0 => ProcedureColumnType::Unknown,
+            1 => ProcedureColumnType::Input,
+            2 => ProcedureColumnType::InputOutput,
+            3 => ProcedureColumnType::ResultColumn,
+            4 => ProcedureColumnType::Output,
+            5 => ProcedureColumnType::ReturnValue,
+            other => ProcedureColumnType::Other(other),
+        }
+    }
+}
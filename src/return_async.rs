@@ -0,0 +1,48 @@
+use sys::*;
+
+/// Holds result and indicates the overall success or failure of a function, which may be executed
+/// asynchronously.
+#[derive(Debug)]
+#[must_use]
+pub enum ReturnAsync<T, P = (), E = ()> {
+    /// The function has been executed successfully. Holds result.
+    Success(T),
+    /// The function has been executed successfully. There have been warnings. Holds result.
+    Info(T),
+    /// The function is still executing asynchronously. Poll again to drive it to completion.
+    StillExecuting(P),
+    /// No more data was available
+    NoData(E),
+    /// An error occured.
+    Error(E),
+}
+
+impl<T, P, E> ReturnAsync<T, P, E> {
+    /// Maps a `ReturnAsync<T,P,E>` to `ReturnAsync<U,P,E>` by applying a function to a contained
+    /// `Success` or `Info` value, leaving a `StillExecuting`, `NoData` or `Error` value untouched.
+    pub fn map<F, U>(self, f: F) -> ReturnAsync<U, P, E>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            ReturnAsync::Success(t) => ReturnAsync::Success(f(t)),
+            ReturnAsync::Info(t) => ReturnAsync::Info(f(t)),
+            ReturnAsync::StillExecuting(p) => ReturnAsync::StillExecuting(p),
+            ReturnAsync::NoData(e) => ReturnAsync::NoData(e),
+            ReturnAsync::Error(e) => ReturnAsync::Error(e),
+        }
+    }
+}
+
+impl From<SQLRETURN> for ReturnAsync<()> {
+    fn from(source: SQLRETURN) -> ReturnAsync<()> {
+        match source {
+            SQL_SUCCESS => ReturnAsync::Success(()),
+            SQL_SUCCESS_WITH_INFO => ReturnAsync::Info(()),
+            SQL_STILL_EXECUTING => ReturnAsync::StillExecuting(()),
+            SQL_ERROR => ReturnAsync::Error(()),
+            SQL_NO_DATA => ReturnAsync::NoData(()),
+            other => panic!("Unexpected SQLRETURN value: {:?}", other),
+        }
+    }
+}
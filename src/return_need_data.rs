@@ -0,0 +1,49 @@
+use sys::*;
+
+/// Holds result and indicates the overall success or failure of a function, which may require
+/// data for parameters bound as data-at-execution.
+#[derive(Debug)]
+#[must_use]
+pub enum ReturnNeedData<T, N = (), E = ()> {
+    /// The function has been executed successfully. Holds result.
+    Success(T),
+    /// The function has been executed successfully. There have been warnings. Holds result.
+    Info(T),
+    /// The data source needs data for a parameter bound as data-at-execution.
+    NeedData(N),
+    /// No more data was available
+    NoData(E),
+    /// An error occured.
+    Error(E),
+}
+
+impl<T, N, E> ReturnNeedData<T, N, E> {
+    /// Maps a `ReturnNeedData<T,N,E>` to `ReturnNeedData<U,N,E>` by applying a function to a
+    /// contained `Success` or `Info` value, leaving a `NeedData`, `NoData` or `Error` value
+    /// untouched.
+    pub fn map<F, U>(self, f: F) -> ReturnNeedData<U, N, E>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            ReturnNeedData::Success(t) => ReturnNeedData::Success(f(t)),
+            ReturnNeedData::Info(t) => ReturnNeedData::Info(f(t)),
+            ReturnNeedData::NeedData(n) => ReturnNeedData::NeedData(n),
+            ReturnNeedData::NoData(e) => ReturnNeedData::NoData(e),
+            ReturnNeedData::Error(e) => ReturnNeedData::Error(e),
+        }
+    }
+}
+
+impl From<SQLRETURN> for ReturnNeedData<()> {
+    fn from(source: SQLRETURN) -> ReturnNeedData<()> {
+        match source {
+            SQL_SUCCESS => ReturnNeedData::Success(()),
+            SQL_SUCCESS_WITH_INFO => ReturnNeedData::Info(()),
+            SQL_NEED_DATA => ReturnNeedData::NeedData(()),
+            SQL_ERROR => ReturnNeedData::Error(()),
+            SQL_NO_DATA => ReturnNeedData::NoData(()),
+            other => panic!("Unexpected SQLRETURN value: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use sys::*;
+
+/// Minimum time a row identifier reported by `Statement::special_columns` must remain valid.
+///
+/// See [SQLSpecialColumns Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlspecialcolumns-function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowIdScope {
+    /// Only guaranteed to be valid while positioned on the row.
+    CurrentRow,
+    /// Valid for the duration of the current transaction.
+    Transaction,
+    /// Valid for the duration of the session, across transaction boundaries.
+    Session,
+}
+
+impl RowIdScope {
+    /// Value passed to the driver as `Scope` argument of `SQLSpecialColumns`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        match *self {
+            RowIdScope::CurrentRow => 0,
+            RowIdScope::Transaction => 1,
+            RowIdScope::Session => 2,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use sys::*;
+
+/// Status of a single row within the row set of a block cursor.
+///
+/// See [SQLFetch Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlfetch-function#row-status-array
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowStatus {
+    /// The row was successfully fetched and has not changed since it was last fetched.
+    Success,
+    /// The row was successfully fetched, but a warning was returned about it.
+    SuccessWithInfo,
+    /// An error occurred while fetching the row.
+    Error,
+    /// The row was successfully fetched and has changed since it was last fetched.
+    Updated,
+    /// The row has been deleted since it was last fetched.
+    Deleted,
+    /// The row was inserted by `SQLBulkOperations`.
+    Added,
+    /// The row set overlapped the end of the result set, and no row was returned for this element.
+    NoRow,
+    /// A status not defined by ODBC, returned by the driver.
+    Other(SQLUSMALLINT),
+}
+
+impl From<SQLUSMALLINT> for RowStatus {
+    fn from(source: SQLUSMALLINT) -> RowStatus {
+        match source {
+            0 => RowStatus::Success,
+            1 => RowStatus::Deleted,
+            2 => RowStatus::Updated,
+            3 => RowStatus::NoRow,
+            4 => RowStatus::Added,
+            5 => RowStatus::Error,
+            6 => RowStatus::SuccessWithInfo,
+            other => RowStatus::Other(other),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use sys::*;
+
+/// Operation performed by `Statement::set_pos` on rows of the current row set.
+///
+/// See [SQLSetPos Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlsetpos-function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetPosOperation {
+    /// Positions the cursor on the row, e.g. in order to call `get_data` for it.
+    Position,
+    /// Refreshes the bound buffers with the current values of the row in the data source.
+    Refresh,
+    /// Updates the row in the data source with the values of the bound buffers.
+    Update,
+    /// Deletes the row from the data source.
+    Delete,
+}
+
+impl SetPosOperation {
+    /// Value passed to the driver as `Operation` argument of `SQLSetPos`
+    pub(crate) fn value(&self) -> SQLUSMALLINT {
+        match *self {
+            SetPosOperation::Position => 0,
+            SetPosOperation::Refresh => 1,
+            SetPosOperation::Update => 2,
+            SetPosOperation::Delete => 3,
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use sys::*;
 use std::ffi::CStr;
+use std::os::raw::c_char;
 use std::ptr::null;
+use std::slice::from_raw_parts;
 
 /// A type implementing this trait can be passed as a string argument in API calls
 pub unsafe trait SqlStr {
@@ -93,3 +95,54 @@ unsafe impl SqlStr for str {
         self.len() as SQLINTEGER
     }
 }
+
+/// Owned copy of the text referenced by a `SqlStr`, e.g. to pass it again to a function which
+/// has been executing asynchronously. Null terminated text keeps its terminating zero, so even an
+/// empty `CStr` is passed as a valid pointer.
+#[derive(Debug)]
+pub(crate) enum OwnedSqlStr {
+    /// Text including its terminating zero, passed with `SQL_NTS`
+    NullTerminated(Vec<SQLCHAR>),
+    /// Text passed with an explicit length
+    Sized(Vec<SQLCHAR>),
+}
+
+unsafe impl SqlStr for OwnedSqlStr {
+    fn as_text_ptr(&self) -> *const SQLCHAR {
+        match *self {
+            OwnedSqlStr::NullTerminated(ref text) => text.as_ptr(),
+            OwnedSqlStr::Sized(ref text) => text[..].as_text_ptr(),
+        }
+    }
+
+    fn text_length(&self) -> SQLSMALLINT {
+        match *self {
+            OwnedSqlStr::NullTerminated(_) => SQL_NTS,
+            OwnedSqlStr::Sized(ref text) => text[..].text_length(),
+        }
+    }
+
+    fn text_length_int(&self) -> SQLINTEGER {
+        match *self {
+            OwnedSqlStr::NullTerminated(_) => SQL_NTSL,
+            OwnedSqlStr::Sized(ref text) => text[..].text_length_int(),
+        }
+    }
+}
+
+/// Copies the text referenced by `text`, e.g. to pass it again to a function which has been
+/// executing asynchronously.
+pub(crate) fn to_owned<T>(text: &T) -> OwnedSqlStr
+where
+    T: SqlStr + ?Sized,
+{
+    let length = text.text_length_int();
+    if length == SQL_NTSL {
+        let ptr: *const c_char = text.as_text_ptr() as *const c_char;
+        OwnedSqlStr::NullTerminated(unsafe { CStr::from_ptr(ptr).to_bytes_with_nul().to_vec() })
+    } else if length == 0 || text.as_text_ptr().is_null() {
+        OwnedSqlStr::Sized(Vec::new())
+    } else {
+        OwnedSqlStr::Sized(unsafe { from_raw_parts(text.as_text_ptr(), length as usize).to_vec() })
+    }
+}
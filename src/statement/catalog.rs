@@ -0,0 +1,531 @@
+//! Catalog functions returning information about the objects stored in the data source as result
+//! sets.
+//!
+//! String arguments are either ordinary arguments or search patterns, in which `_` matches any
+//! single character and `%` matches any sequence of zero or more characters. Passing an empty
+//! `str` or `[u8]` passes a NULL pointer to the driver, which does not restrict the result by this
+//! argument. Pass an empty `CStr` to match objects without e.g. a catalog or schema.
+//!
+//! See [Catalog Functions][1]
+//! [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/catalog-functions
+use super::*;
+
+impl<'con, 'param, 'col, CT> Statement<'con, 'param, 'col, NoCursor, Unprepared, CT> {
+    /// Returns the list of tables, catalogs, schemas or table types stored in the data source.
+    ///
+    /// # Arguments
+    /// * `catalog_name` - Search pattern for catalog names. `%` lists all catalogs, if schema and
+    ///   table name are empty strings.
+    /// * `schema_name` - Search pattern for schema names
+    /// * `table_name` - Search pattern for table names
+    /// * `table_type` - Comma separated list of table types to match, e.g. `'TABLE','VIEW'`
+    ///
+    /// # Result set
+    ///
+    /// | # | Column        | Type    |
+    /// |---|---------------|---------|
+    /// | 1 | `TABLE_CAT`   | Varchar |
+    /// | 2 | `TABLE_SCHEM` | Varchar |
+    /// | 3 | `TABLE_NAME`  | Varchar |
+    /// | 4 | `TABLE_TYPE`  | Varchar |
+    /// | 5 | `REMARKS`     | Varchar |
+    ///
+    /// See [SQLTables Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqltables-function
+    pub fn tables<C, S, T, Y>(
+        mut self,
+        catalog_name: &C,
+        schema_name: &S,
+        table_name: &T,
+        table_type: &Y,
+    ) -> Return<ResultSet<'con, 'param, 'col, Unprepared, CT>, Self>
+    where
+        C: SqlStr + ?Sized,
+        S: SqlStr + ?Sized,
+        T: SqlStr + ?Sized,
+        Y: SqlStr + ?Sized,
+    {
+        match self.handle.tables(catalog_name, schema_name, table_name, table_type) {
+            Success(()) => Success(self.transit()),
+            Info(()) => Info(self.transit()),
+            Error(()) => Error(self),
+        }
+    }
+
+    /// Returns the list of columns of the matching tables.
+    ///
+    /// # Arguments
+    /// * `catalog_name` - Catalog name. Not a search pattern.
+    /// * `schema_name` - Search pattern for schema names
+    /// * `table_name` - Search pattern for table names
+    /// * `column_name` - Search pattern for column names
+    ///
+    /// # Result set
+    ///
+    /// | #  | Column              | Type                |
+    /// |----|---------------------|---------------------|
+    /// | 1  | `TABLE_CAT`         | Varchar             |
+    /// | 2  | `TABLE_SCHEM`       | Varchar             |
+    /// | 3  | `TABLE_NAME`        | Varchar not NULL    |
+    /// | 4  | `COLUMN_NAME`       | Varchar not NULL    |
+    /// | 5  | `DATA_TYPE`         | Smallint not NULL   |
+    /// | 6  | `TYPE_NAME`         | Varchar not NULL    |
+    /// | 7  | `COLUMN_SIZE`       | Integer             |
+    /// | 8  | `BUFFER_LENGTH`     | Integer             |
+    /// | 9  | `DECIMAL_DIGITS`    | Smallint            |
+    /// | 10 | `NUM_PREC_RADIX`    | Smallint            |
+    /// | 11 | `NULLABLE`          | Smallint not NULL   |
+    /// | 12 | `REMARKS`           | Varchar             |
+    /// | 13 | `COLUMN_DEF`        | Varchar             |
+    /// | 14 | `SQL_DATA_TYPE`     | Smallint not NULL   |
+    /// | 15 | `SQL_DATETIME_SUB`  | Smallint            |
+    /// | 16 | `CHAR_OCTET_LENGTH` | Integer             |
+    /// | 17 | `ORDINAL_POSITION`  | Integer not NULL    |
+    /// | 18 | `IS_NULLABLE`       | Varchar             |
+    ///
+    /// See [SQLColumns Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlcolumns-function
+    pub fn columns<C, S, T, N>(
+        mut self,
+        catalog_name: &C,
+        schema_name: &S,
+        table_name: &T,
+        column_name: &N,
+    ) -> Return<ResultSet<'con, 'param, 'col, Unprepared, CT>, Self>
+    where
+        C: SqlStr + ?Sized,
+        S: SqlStr + ?Sized,
+        T: SqlStr + ?Sized,
+        N: SqlStr + ?Sized,
+    {
+        match self.handle.columns(catalog_name, schema_name, table_name, column_name) {
+            Success(()) => Success(self.transit()),
+            Info(()) => Info(self.transit()),
+            Error(()) => Error(self),
+        }
+    }
+
+    /// Returns the privileges granted on the matching tables.
+    ///
+    /// # Arguments
+    /// * `catalog_name` - Catalog name. Not a search pattern.
+    /// * `schema_name` - Search pattern for schema names
+    /// * `table_name` - Search pattern for table names
+    ///
+    /// # Result set
+    ///
+    /// | # | Column         | Type             |
+    /// |---|----------------|------------------|
+    /// | 1 | `TABLE_CAT`    | Varchar          |
+    /// | 2 | `TABLE_SCHEM`  | Varchar          |
+    /// | 3 | `TABLE_NAME`   | Varchar not NULL |
+    /// | 4 | `GRANTOR`      | Varchar          |
+    /// | 5 | `GRANTEE`      | Varchar not NULL |
+    /// | 6 | `PRIVILEGE`    | Varchar not NULL |
+    /// | 7 | `IS_GRANTABLE` | Varchar          |
+    ///
+    /// See [SQLTablePrivileges Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqltableprivileges-function
+    pub fn table_privileges<C, S, T>(
+        mut self,
+        catalog_name: &C,
+        schema_name: &S,
+        table_name: &T,
+    ) -> Return<ResultSet<'con, 'param, 'col, Unprepared, CT>, Self>
+    where
+        C: SqlStr + ?Sized,
+        S: SqlStr + ?Sized,
+        T: SqlStr + ?Sized,
+    {
+        match self.handle.table_privileges(catalog_name, schema_name, table_name) {
+            Success(()) => Success(self.transit()),
+            Info(()) => Info(self.transit()),
+            Error(()) => Error(self),
+        }
+    }
+
+    /// Returns the privileges granted on the matching columns of a single table.
+    ///
+    /// # Arguments
+    /// * `catalog_name` - Catalog name. Not a search pattern.
+    /// * `schema_name` - Schema name. Not a search pattern.
+    /// * `table_name` - Table name. Not a search pattern.
+    /// * `column_name` - Search pattern for column names
+    ///
+    /// # Result set
+    ///
+    /// | # | Column         | Type             |
+    /// |---|----------------|------------------|
+    /// | 1 | `TABLE_CAT`    | Varchar          |
+    /// | 2 | `TABLE_SCHEM`  | Varchar          |
+    /// | 3 | `TABLE_NAME`   | Varchar not NULL |
+    /// | 4 | `COLUMN_NAME`  | Varchar not NULL |
+    /// | 5 | `GRANTOR`      | Varchar          |
+    /// | 6 | `GRANTEE`      | Varchar not NULL |
+    /// | 7 | `PRIVILEGE`    | Varchar not NULL |
+    /// | 8 | `IS_GRANTABLE` | Varchar          |
+    ///
+    /// See [SQLColumnPrivileges Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlcolumnprivileges-function
+    pub fn column_privileges<C, S, T, N>(
+        mut self,
+        catalog_name: &C,
+        schema_name: &S,
+        table_name: &T,
+        column_name: &N,
+    ) -> Return<ResultSet<'con, 'param, 'col, Unprepared, CT>, Self>
+    where
+        C: SqlStr + ?Sized,
+        S: SqlStr + ?Sized,
+        T: SqlStr + ?Sized,
+        N: SqlStr + ?Sized,
+    {
+        match self.handle.column_privileges(catalog_name, schema_name, table_name, column_name) {
+            Success(()) => Success(self.transit()),
+            Info(()) => Info(self.transit()),
+            Error(()) => Error(self),
+        }
+    }
+
+    /// Returns the columns making up the primary key of a single table.
+    ///
+    /// # Arguments
+    /// * `catalog_name` - Catalog name. Not a search pattern.
+    /// * `schema_name` - Schema name. Not a search pattern.
+    /// * `table_name` - Table name. Not a search pattern.
+    ///
+    /// # Result set
+    ///
+    /// | # | Column        | Type              |
+    /// |---|---------------|-------------------|
+    /// | 1 | `TABLE_CAT`   | Varchar           |
+    /// | 2 | `TABLE_SCHEM` | Varchar           |
+    /// | 3 | `TABLE_NAME`  | Varchar not NULL  |
+    /// | 4 | `COLUMN_NAME` | Varchar not NULL  |
+    /// | 5 | `KEY_SEQ`     | Smallint not NULL |
+    /// | 6 | `PK_NAME`     | Varchar           |
+    ///
+    /// See [SQLPrimaryKeys Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlprimarykeys-function
+    pub fn primary_keys<C, S, T>(
+        mut self,
+        catalog_name: &C,
+        schema_name: &S,
+        table_name: &T,
+    ) -> Return<ResultSet<'con, 'param, 'col, Unprepared, CT>, Self>
+    where
+        C: SqlStr + ?Sized,
+        S: SqlStr + ?Sized,
+        T: SqlStr + ?Sized,
+    {
+        match self.handle.primary_keys(catalog_name, schema_name, table_name) {
+            Success(()) => Success(self.transit()),
+            Info(()) => Info(self.transit()),
+            Error(()) => Error(self),
+        }
+    }
+
+    /// Returns foreign keys. If the primary key table is specified, the foreign keys referring to
+    /// its primary key are returned. If the foreign key table is specified, the foreign keys it
+    /// contains are returned. If both are specified, the foreign key of the foreign key table
+    /// referring to the primary key of the primary key table is returned. None of the arguments
+    /// are search patterns.
+    ///
+    /// # Result set
+    ///
+    /// | #  | Column          | Type              |
+    /// |----|-----------------|-------------------|
+    /// | 1  | `PKTABLE_CAT`   | Varchar           |
+    /// | 2  | `PKTABLE_SCHEM` | Varchar           |
+    /// | 3  | `PKTABLE_NAME`  | Varchar not NULL  |
+    /// | 4  | `PKCOLUMN_NAME` | Varchar not NULL  |
+    /// | 5  | `FKTABLE_CAT`   | Varchar           |
+    /// | 6  | `FKTABLE_SCHEM` | Varchar           |
+    /// | 7  | `FKTABLE_NAME`  | Varchar not NULL  |
+    /// | 8  | `FKCOLUMN_NAME` | Varchar not NULL  |
+    /// | 9  | `KEY_SEQ`       | Smallint not NULL |
+    /// | 10 | `UPDATE_RULE`   | Smallint          |
+    /// | 11 | `DELETE_RULE`   | Smallint          |
+    /// | 12 | `FK_NAME`       | Varchar           |
+    /// | 13 | `PK_NAME`       | Varchar           |
+    /// | 14 | `DEFERRABILITY` | Smallint          |
+    ///
+    /// See [SQLForeignKeys Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlforeignkeys-function
+    pub fn foreign_keys<PC, PS, PT, FC, FS, FT>(
+        mut self,
+        pk_catalog_name: &PC,
+        pk_schema_name: &PS,
+        pk_table_name: &PT,
+        fk_catalog_name: &FC,
+        fk_schema_name: &FS,
+        fk_table_name: &FT,
+    ) -> Return<ResultSet<'con, 'param, 'col, Unprepared, CT>, Self>
+    where
+        PC: SqlStr + ?Sized,
+        PS: SqlStr + ?Sized,
+        PT: SqlStr + ?Sized,
+        FC: SqlStr + ?Sized,
+        FS: SqlStr + ?Sized,
+        FT: SqlStr + ?Sized,
+    {
+        match self.handle.foreign_keys(
+            pk_catalog_name,
+            pk_schema_name,
+            pk_table_name,
+            fk_catalog_name,
+            fk_schema_name,
+            fk_table_name,
+        ) {
+            Success(()) => Success(self.transit()),
+            Info(()) => Info(self.transit()),
+            Error(()) => Error(self),
+        }
+    }
+
+    /// Returns statistics about a single table and its indexes.
+    ///
+    /// # Arguments
+    /// * `catalog_name` - Catalog name. Not a search pattern.
+    /// * `schema_name` - Schema name. Not a search pattern.
+    /// * `table_name` - Table name. Not a search pattern.
+    /// * `index_type` - Whether to return only unique or all indexes
+    /// * `accuracy` - Accuracy of the `CARDINALITY` and `PAGES` columns
+    ///
+    /// # Result set
+    ///
+    /// | #  | Column             | Type              |
+    /// |----|--------------------|-------------------|
+    /// | 1  | `TABLE_CAT`        | Varchar           |
+    /// | 2  | `TABLE_SCHEM`      | Varchar           |
+    /// | 3  | `TABLE_NAME`       | Varchar not NULL  |
+    /// | 4  | `NON_UNIQUE`       | Smallint          |
+    /// | 5  | `INDEX_QUALIFIER`  | Varchar           |
+    /// | 6  | `INDEX_NAME`       | Varchar           |
+    /// | 7  | `TYPE`             | Smallint not NULL |
+    /// | 8  | `ORDINAL_POSITION` | Smallint          |
+    /// | 9  | `COLUMN_NAME`      | Varchar           |
+    /// | 10 | `ASC_OR_DESC`      | Char(1)           |
+    /// | 11 | `CARDINALITY`      | Integer           |
+    /// | 12 | `PAGES`            | Integer           |
+    /// | 13 | `FILTER_CONDITION` | Varchar           |
+    ///
+    /// See [SQLStatistics Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlstatistics-function
+    pub fn statistics<C, S, T>(
+        mut self,
+        catalog_name: &C,
+        schema_name: &S,
+        table_name: &T,
+        index_type: IndexType,
+        accuracy: Accuracy,
+    ) -> Return<ResultSet<'con, 'param, 'col, Unprepared, CT>, Self>
+    where
+        C: SqlStr + ?Sized,
+        S: SqlStr + ?Sized,
+        T: SqlStr + ?Sized,
+    {
+        match self.handle.statistics(
+            catalog_name,
+            schema_name,
+            table_name,
+            index_type.value(),
+            accuracy.value(),
+        ) {
+            Success(()) => Success(self.transit()),
+            Info(()) => Info(self.transit()),
+            Error(()) => Error(self),
+        }
+    }
+
+    /// Returns the optimal set of columns uniquely identifying a row, or the columns updated
+    /// automatically whenever a row is updated.
+    ///
+    /// # Arguments
+    /// * `identifier_type` - Kind of columns to return
+    /// * `catalog_name` - Catalog name. Not a search pattern.
+    /// * `schema_name` - Schema name. Not a search pattern.
+    /// * `table_name` - Table name. Not a search pattern.
+    /// * `scope` - Minimum time the row identifier must remain valid
+    /// * `nullable` - Whether to include columns which may be NULL
+    ///
+    /// # Result set
+    ///
+    /// | # | Column           | Type              |
+    /// |---|------------------|-------------------|
+    /// | 1 | `SCOPE`          | Smallint          |
+    /// | 2 | `COLUMN_NAME`    | Varchar not NULL  |
+    /// | 3 | `DATA_TYPE`      | Smallint not NULL |
+    /// | 4 | `TYPE_NAME`      | Varchar not NULL  |
+    /// | 5 | `COLUMN_SIZE`    | Integer           |
+    /// | 6 | `BUFFER_LENGTH`  | Integer           |
+    /// | 7 | `DECIMAL_DIGITS` | Smallint          |
+    /// | 8 | `PSEUDO_COLUMN`  | Smallint          |
+    ///
+    /// See [SQLSpecialColumns Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlspecialcolumns-function
+    pub fn special_columns<C, S, T>(
+        mut self,
+        identifier_type: IdentifierType,
+        catalog_name: &C,
+        schema_name: &S,
+        table_name: &T,
+        scope: RowIdScope,
+        nullable: NullableColumns,
+    ) -> Return<ResultSet<'con, 'param, 'col, Unprepared, CT>, Self>
+    where
+        C: SqlStr + ?Sized,
+        S: SqlStr + ?Sized,
+        T: SqlStr + ?Sized,
+    {
+        match self.handle.special_columns(
+            identifier_type.value(),
+            catalog_name,
+            schema_name,
+            table_name,
+            scope.value(),
+            nullable.value(),
+        ) {
+            Success(()) => Success(self.transit()),
+            Info(()) => Info(self.transit()),
+            Error(()) => Error(self),
+        }
+    }
+
+    /// Returns the list of stored procedures.
+    ///
+    /// # Arguments
+    /// * `catalog_name` - Catalog name. Not a search pattern.
+    /// * `schema_name` - Search pattern for schema names
+    /// * `proc_name` - Search pattern for procedure names
+    ///
+    /// # Result set
+    ///
+    /// | # | Column              | Type             |
+    /// |---|---------------------|------------------|
+    /// | 1 | `PROCEDURE_CAT`     | Varchar          |
+    /// | 2 | `PROCEDURE_SCHEM`   | Varchar          |
+    /// | 3 | `PROCEDURE_NAME`    | Varchar not NULL |
+    /// | 4 | `NUM_INPUT_PARAMS`  | Reserved         |
+    /// | 5 | `NUM_OUTPUT_PARAMS` | Reserved         |
+    /// | 6 | `NUM_RESULT_SETS`   | Reserved         |
+    /// | 7 | `REMARKS`           | Varchar          |
+    /// | 8 | `PROCEDURE_TYPE`    | Smallint         |
+    ///
+    /// See [SQLProcedures Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlprocedures-function
+    pub fn procedures<C, S, P>(
+        mut self,
+        catalog_name: &C,
+        schema_name: &S,
+        proc_name: &P,
+    ) -> Return<ResultSet<'con, 'param, 'col, Unprepared, CT>, Self>
+    where
+        C: SqlStr + ?Sized,
+        S: SqlStr + ?Sized,
+        P: SqlStr + ?Sized,
+    {
+        match self.handle.procedures(catalog_name, schema_name, proc_name) {
+            Success(()) => Success(self.transit()),
+            Info(()) => Info(self.transit()),
+            Error(()) => Error(self),
+        }
+    }
+
+    /// Returns the parameters, the return value and the result set columns of the matching
+    /// stored procedures. Use `ProcedureColumnType::from` to interpret the `COLUMN_TYPE` column.
+    ///
+    /// # Arguments
+    /// * `catalog_name` - Catalog name. Not a search pattern.
+    /// * `schema_name` - Search pattern for schema names
+    /// * `proc_name` - Search pattern for procedure names
+    /// * `column_name` - Search pattern for column names
+    ///
+    /// # Result set
+    ///
+    /// | #  | Column              | Type              |
+    /// |----|---------------------|-------------------|
+    /// | 1  | `PROCEDURE_CAT`     | Varchar           |
+    /// | 2  | `PROCEDURE_SCHEM`   | Varchar           |
+    /// | 3  | `PROCEDURE_NAME`    | Varchar not NULL  |
+    /// | 4  | `COLUMN_NAME`       | Varchar not NULL  |
+    /// | 5  | `COLUMN_TYPE`       | Smallint not NULL |
+    /// | 6  | `DATA_TYPE`         | Smallint not NULL |
+    /// | 7  | `TYPE_NAME`         | Varchar not NULL  |
+    /// | 8  | `COLUMN_SIZE`       | Integer           |
+    /// | 9  | `BUFFER_LENGTH`     | Integer           |
+    /// | 10 | `DECIMAL_DIGITS`    | Smallint          |
+    /// | 11 | `NUM_PREC_RADIX`    | Smallint          |
+    /// | 12 | `NULLABLE`          | Smallint not NULL |
+    /// | 13 | `REMARKS`           | Varchar           |
+    /// | 14 | `COLUMN_DEF`        | Varchar           |
+    /// | 15 | `SQL_DATA_TYPE`     | Smallint not NULL |
+    /// | 16 | `SQL_DATETIME_SUB`  | Smallint          |
+    /// | 17 | `CHAR_OCTET_LENGTH` | Integer           |
+    /// | 18 | `ORDINAL_POSITION`  | Integer not NULL  |
+    /// | 19 | `IS_NULLABLE`       | Varchar           |
+    ///
+    /// See [SQLProcedureColumns Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlprocedurecolumns-function
+    pub fn procedure_columns<C, S, P, N>(
+        mut self,
+        catalog_name: &C,
+        schema_name: &S,
+        proc_name: &P,
+        column_name: &N,
+    ) -> Return<ResultSet<'con, 'param, 'col, Unprepared, CT>, Self>
+    where
+        C: SqlStr + ?Sized,
+        S: SqlStr + ?Sized,
+        P: SqlStr + ?Sized,
+        N: SqlStr + ?Sized,
+    {
+        match self.handle.procedure_columns(catalog_name, schema_name, proc_name, column_name) {
+            Success(()) => Success(self.transit()),
+            Info(()) => Info(self.transit()),
+            Error(()) => Error(self),
+        }
+    }
+
+    /// Returns information about the data types supported by the data source. `None` returns all
+    /// types, otherwise only the types matching the SQL data type of `data_type` are returned.
+    /// Use `TypeInfo::read` to decode the rows.
+    ///
+    /// # Result set
+    ///
+    /// | #  | Column               | Type              |
+    /// |----|----------------------|-------------------|
+    /// | 1  | `TYPE_NAME`          | Varchar not NULL  |
+    /// | 2  | `DATA_TYPE`          | Smallint not NULL |
+    /// | 3  | `COLUMN_SIZE`        | Integer           |
+    /// | 4  | `LITERAL_PREFIX`     | Varchar           |
+    /// | 5  | `LITERAL_SUFFIX`     | Varchar           |
+    /// | 6  | `CREATE_PARAMS`      | Varchar           |
+    /// | 7  | `NULLABLE`           | Smallint not NULL |
+    /// | 8  | `CASE_SENSITIVE`     | Smallint not NULL |
+    /// | 9  | `SEARCHABLE`         | Smallint not NULL |
+    /// | 10 | `UNSIGNED_ATTRIBUTE` | Smallint          |
+    /// | 11 | `FIXED_PREC_SCALE`   | Smallint not NULL |
+    /// | 12 | `AUTO_UNIQUE_VALUE`  | Smallint          |
+    /// | 13 | `LOCAL_TYPE_NAME`    | Varchar           |
+    /// | 14 | `MINIMUM_SCALE`      | Smallint          |
+    /// | 15 | `MAXIMUM_SCALE`      | Smallint          |
+    /// | 16 | `SQL_DATA_TYPE`      | Smallint not NULL |
+    /// | 17 | `SQL_DATETIME_SUB`   | Smallint          |
+    /// | 18 | `NUM_PREC_RADIX`     | Integer           |
+    /// | 19 | `INTERVAL_PRECISION` | Smallint          |
+    ///
+    /// See [SQLGetTypeInfo Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlgettypeinfo-function
+    pub fn type_info(
+        mut self,
+        data_type: Option<DataType>,
+    ) -> Return<ResultSet<'con, 'param, 'col, Unprepared, CT>, Self> {
+        let data_type = data_type.map_or(SQL_UNKNOWN_TYPE, |t| t.sql_data_type());
+        match self.handle.type_info(data_type) {
+            Success(()) => Success(self.transit()),
+            Info(()) => Info(self.transit()),
+            Error(()) => Error(self),
+        }
+    }
+}
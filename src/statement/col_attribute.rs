@@ -0,0 +1,101 @@
+//! Descriptor fields of the result set columns, which are available for open cursors as well as
+//! prepared statements.
+//!
+//! See [SQLColAttribute Function][1]
+//! [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlcolattribute-function
+use super::*;
+
+/// Implemented by the combinations of cursor state and access plan, in which the columns of the
+/// result set can be described. These are open cursors and prepared statements.
+pub trait DescribesColumns {}
+impl<C: CursorState, A> DescribesColumns for (C, A) {}
+impl DescribesColumns for (NoCursor, Prepared) {}
+
+impl<'con, 'param, 'col, C, A, CT> Statement<'con, 'param, 'col, C, A, CT>
+where
+    (C, A): DescribesColumns,
+{
+    /// Returns a numeric descriptor field of a result set column. Columns start at `1`.
+    ///
+    /// See [SQLColAttribute Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlcolattribute-function
+    pub fn numeric_col_attribute(
+        &mut self,
+        column_number: SQLUSMALLINT,
+        field: NumericColAttribute,
+    ) -> Return<SQLLEN> {
+        self.handle.numeric_col_attribute(column_number, field.value())
+    }
+
+    /// Writes a character descriptor field of a result set column into `value`, including a
+    /// terminating zero. `indicator` receives the length of the field in bytes, excluding the
+    /// terminating zero. `Info` is returned if the field has been truncated.
+    ///
+    /// See [SQLColAttribute Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlcolattribute-function
+    pub fn string_col_attribute<T>(
+        &mut self,
+        column_number: SQLUSMALLINT,
+        field: StringColAttribute,
+        value: &mut T,
+        indicator: &mut SQLSMALLINT,
+    ) -> Return<()>
+    where
+        T: OutputBuffer + ?Sized,
+    {
+        self.handle.string_col_attribute(column_number, field.value(), value, indicator)
+    }
+
+    /// Type of a result set column, assembled from its concise type, length, precision and scale.
+    /// `None` if the type is unknown or not covered by `DataType`.
+    pub fn col_data_type(&mut self, column_number: SQLUSMALLINT) -> Return<Option<DataType>> {
+        use NumericColAttribute::{ConciseType, Length, Precision, Scale};
+        let mut values = [0; 4];
+        let mut info = false;
+        for (value, &field) in values.iter_mut().zip(&[ConciseType, Length, Precision, Scale]) {
+            match self.numeric_col_attribute(column_number, field) {
+                Success(v) => *value = v,
+                Info(v) => {
+                    *value = v;
+                    info = true;
+                }
+                Error(()) => return Error(()),
+            }
+        }
+        let [concise_type, length, precision, scale] = values;
+        let data_type = DataType::from_raw(
+            concise_type as SQLSMALLINT,
+            length as SQLULEN,
+            scale as SQLSMALLINT,
+        ).map(|data_type| match data_type {
+            // Precision rather than length is the column size of numeric types and the number of
+            // fractional seconds digits of time types.
+            DataType::Numeric(_, scale) => DataType::Numeric(precision as SQLULEN, scale),
+            DataType::Decimal(_, scale) => DataType::Decimal(precision as SQLULEN, scale),
+            DataType::Time(_) => DataType::Time(precision as SQLSMALLINT),
+            DataType::Timestamp(_) => DataType::Timestamp(precision as SQLSMALLINT),
+            other => other,
+        });
+        if info {
+            Info(data_type)
+        } else {
+            Success(data_type)
+        }
+    }
+
+    /// Whether a result set column accepts NULL values.
+    pub fn col_nullable(&mut self, column_number: SQLUSMALLINT) -> Return<Nullable> {
+        self.numeric_col_attribute(column_number, NumericColAttribute::Nullable)
+            .map(|raw| match raw {
+                0 => SQL_NO_NULLS,
+                1 => SQL_NULLABLE,
+                _ => SQL_NULLABLE_UNKNOWN,
+            })
+    }
+
+    /// How a result set column can be used in `WHERE` clauses.
+    pub fn col_searchable(&mut self, column_number: SQLUSMALLINT) -> Return<Searchable> {
+        self.numeric_col_attribute(column_number, NumericColAttribute::Searchable)
+            .map(Searchable::from_raw)
+    }
+}
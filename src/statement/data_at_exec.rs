@@ -0,0 +1,244 @@
+//! Sending parameter data in chunks at execution time.
+//!
+//! See [Sending Long Data][1]
+//! [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/sending-long-data
+use super::*;
+use ffi::{SQL_DATA_AT_EXEC, SQL_LEN_DATA_AT_EXEC_OFFSET};
+use std::io::{self, Write};
+
+impl<'con, 'param, 'col, S, A, CT> Statement<'con, 'param, 'col, S, A, CT> {
+    /// Binds a parameter marker as data-at-execution. Instead of being read from a buffer, the
+    /// value of the parameter is sent in chunks after the statement has been executed using
+    /// `exec_direct_at_exec` or `execute_at_exec`.
+    ///
+    /// # Arguments
+    /// * `parameter_number` - Index of the marker to bind to the parameter. Starting at `1`
+    /// * `parameter_type` - SQL Type of the parameter
+    /// * `value_type` - C Type of the data which is going to be sent
+    /// * `length` - Total length of the data in bytes. Some drivers require it to be known in
+    ///   advance for long data types. Use `None` if it is not known.
+    /// * `indicator` - Storage for the length indicator. It must stay valid until execution.
+    ///
+    /// # Result
+    /// If the parameter set size of the statement can not be determined, the statement is
+    /// returned unchanged as `Error`.
+    ///
+    /// # Panics
+    /// If the statement has been configured to execute with more than one set of parameters using
+    /// `bind_param_set`.
+    ///
+    /// See [SQLBindParameter Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlbindparameter-function
+    pub fn bind_input_parameter_at_exec<'p>(
+        mut self,
+        parameter_number: SQLUSMALLINT,
+        parameter_type: DataType,
+        value_type: SqlCDataType,
+        length: Option<SQLLEN>,
+        indicator: &'p mut SQLLEN,
+    ) -> Return<Statement<'con, 'p, 'col, S, A, CT>, Self>
+    where
+        'param: 'p,
+    {
+        match self.handle.paramset_size() {
+            Success(paramset_size) | Info(paramset_size) => assert_eq!(
+                paramset_size,
+                1,
+                "Data-at-execution parameters can only hold a single value."
+            ),
+            Error(()) => return Error(self),
+        }
+        *indicator = match length {
+            Some(length) => {
+                assert!(length >= 0, "Length of data-at-execution parameter must not be negative.");
+                SQL_LEN_DATA_AT_EXEC_OFFSET - length
+            }
+            None => SQL_DATA_AT_EXEC,
+        };
+        // The value pointer is never dereferenced by the driver. It is handed back to us by
+        // `SQLParamData` in order to identify the parameter.
+        let token = parameter_number as usize as SQLPOINTER;
+        unsafe {
+            match self.handle.bind_parameter_ptr(
+                parameter_number,
+                SQL_PARAM_INPUT,
+                value_type,
+                parameter_type,
+                token,
+                0,
+                indicator,
+            ) {
+                Success(()) => Success(self.transit()),
+                Info(()) => Info(self.transit()),
+                Error(()) => Error(self.transit()),
+            }
+        }
+    }
+
+    /// `true` if the last call to `exec_direct`, `execute` or their asynchronous counterparts
+    /// failed, because the driver asked for the data of parameters bound with
+    /// `bind_input_parameter_at_exec`. These functions can not send it, so they cancel the
+    /// execution without generating a diagnostic record. Use `exec_direct_at_exec` or
+    /// `execute_at_exec` instead.
+    pub fn rejected_data_at_exec(&self) -> bool {
+        self.handle.rejected_need_data()
+    }
+}
+
+impl<'con, 'param, 'col, CT> Statement<'con, 'param, 'col, NoCursor, Unprepared, CT> {
+    /// Same as `exec_direct`, but allows for parameters bound with `bind_input_parameter_at_exec`.
+    /// If data is needed for any of them, the `Statement` enters the `NeedData` state.
+    #[allow(clippy::type_complexity)]
+    pub fn exec_direct_at_exec<T>(
+        mut self,
+        statement_text: &T,
+    ) -> ReturnNeedData<
+        ResultSet<'con, 'param, 'col, Unprepared, CT>,
+        Statement<'con, 'param, 'col, NeedData, Unprepared, CT>,
+        Statement<'con, 'param, 'col, NoCursor, Unprepared, CT>,
+    >
+    where
+        T: SqlStr + ?Sized,
+    {
+        match self.handle.exec_direct_at_exec(statement_text) {
+            ReturnNeedData::Success(()) => ReturnNeedData::Success(self.transit()),
+            ReturnNeedData::Info(()) => ReturnNeedData::Info(self.transit()),
+            ReturnNeedData::NeedData(()) => ReturnNeedData::NeedData(self.transit()),
+            ReturnNeedData::NoData(()) => ReturnNeedData::NoData(self.transit()),
+            ReturnNeedData::Error(()) => ReturnNeedData::Error(self.transit()),
+        }
+    }
+}
+
+impl<'con, 'param, 'col, CT> Statement<'con, 'param, 'col, NoCursor, Prepared, CT> {
+    /// Same as `execute`, but allows for parameters bound with `bind_input_parameter_at_exec`.
+    /// If data is needed for any of them, the `Statement` enters the `NeedData` state.
+    pub fn execute_at_exec(
+        mut self,
+    ) -> ReturnNeedData<
+        ResultSet<'con, 'param, 'col, Prepared, CT>,
+        Statement<'con, 'param, 'col, NeedData, Prepared, CT>,
+        Self,
+    > {
+        match self.handle.execute_at_exec() {
+            ReturnNeedData::Success(()) => ReturnNeedData::Success(self.transit()),
+            ReturnNeedData::Info(()) => ReturnNeedData::Info(self.transit()),
+            ReturnNeedData::NeedData(()) => ReturnNeedData::NeedData(self.transit()),
+            ReturnNeedData::NoData(()) => ReturnNeedData::NoData(self.transit()),
+            ReturnNeedData::Error(()) => ReturnNeedData::Error(self.transit()),
+        }
+    }
+}
+
+impl<'con, 'param, 'col, A, CT> Statement<'con, 'param, 'col, NeedData, A, CT> {
+    /// Moves on to the next data-at-execution parameter. Returns a `ParamWriter` for it in case of
+    /// `NeedData`. Once data has been sent for all of them, the statement is executed and the
+    /// result of the execution is returned.
+    ///
+    /// See [SQLParamData Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlparamdata-function
+    #[allow(clippy::type_complexity)]
+    pub fn param_data(
+        mut self,
+    ) -> ReturnNeedData<
+        ResultSet<'con, 'param, 'col, A, CT>,
+        ParamWriter<'con, 'param, 'col, A, CT>,
+        Statement<'con, 'param, 'col, NoCursor, A, CT>,
+    > {
+        match self.handle.param_data() {
+            ReturnNeedData::Success(()) => ReturnNeedData::Success(self.transit()),
+            ReturnNeedData::Info(()) => ReturnNeedData::Info(self.transit()),
+            ReturnNeedData::NeedData(token) => ReturnNeedData::NeedData(ParamWriter {
+                parameter_number: token as usize as SQLUSMALLINT,
+                statement: self,
+            }),
+            ReturnNeedData::NoData(()) => ReturnNeedData::NoData(self.transit()),
+            ReturnNeedData::Error(()) => ReturnNeedData::Error(self.transit()),
+        }
+    }
+
+    /// Cancels the execution without sending the remaining data-at-execution parameters.
+    ///
+    /// See [SQLCancel Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlcancel-function
+    pub fn cancel(mut self) -> Return<Statement<'con, 'param, 'col, NoCursor, A, CT>, Self> {
+        match self.handle.cancel() {
+            Success(()) => Success(self.transit()),
+            Info(()) => Info(self.transit()),
+            Error(()) => Error(self.transit()),
+        }
+    }
+}
+
+/// Sends the value of a single data-at-execution parameter in chunks.
+///
+/// Each call to `write` sends one chunk using `SQLPutData`. Call `param_data` to move on to the
+/// next parameter, once all data has been written.
+///
+/// See [SQLPutData Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlputdata-function
+#[derive(Debug)]
+pub struct ParamWriter<'con, 'param, 'col, A, CT = ForwardOnly> {
+    statement: Statement<'con, 'param, 'col, NeedData, A, CT>,
+    parameter_number: SQLUSMALLINT,
+}
+
+impl<'con, 'param, 'col, A, CT> ParamWriter<'con, 'param, 'col, A, CT> {
+    /// Index of the parameter marker data is needed for. Starting at `1`
+    pub fn parameter_number(&self) -> SQLUSMALLINT {
+        self.parameter_number
+    }
+
+    /// Sends a single chunk of data for the parameter. Character data must not contain a
+    /// terminating zero.
+    pub fn put_data(&mut self, data: &[u8]) -> Return<()> {
+        self.statement.handle.put_data(data)
+    }
+
+    /// Sets the parameter to NULL. No other data may be sent for the parameter.
+    pub fn put_null(&mut self) -> Return<()> {
+        self.statement.handle.put_null()
+    }
+
+    /// Finishes the current parameter and moves on to the next one. See `Statement::param_data`.
+    #[allow(clippy::type_complexity)]
+    pub fn param_data(
+        self,
+    ) -> ReturnNeedData<
+        ResultSet<'con, 'param, 'col, A, CT>,
+        ParamWriter<'con, 'param, 'col, A, CT>,
+        Statement<'con, 'param, 'col, NoCursor, A, CT>,
+    > {
+        self.statement.param_data()
+    }
+
+    /// Returns the underlying statement, e.g. in order to `cancel` the execution.
+    pub fn into_statement(self) -> Statement<'con, 'param, 'col, NeedData, A, CT> {
+        self.statement
+    }
+}
+
+impl<'con, 'param, 'col, A, CT> Write for ParamWriter<'con, 'param, 'col, A, CT> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self.put_data(buf) {
+            Success(()) | Info(()) => Ok(buf.len()),
+            Error(()) => Err(io::Error::other(
+                "SQLPutData returned SQL_ERROR. See diagnostics for details.",
+            )),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'con, 'param, 'col, A, CT> Diagnostics for ParamWriter<'con, 'param, 'col, A, CT> {
+    fn diagnostics(
+        &self,
+        rec_number: SQLSMALLINT,
+        message_text: &mut [SQLCHAR],
+    ) -> ReturnOption<DiagResult> {
+        self.statement.diagnostics(rec_number, message_text)
+    }
+}
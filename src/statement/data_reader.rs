@@ -0,0 +1,185 @@
+//! Reading long columns in chunks using repeated calls to `SQLGetData`.
+//!
+//! See [Getting Long Data][1]
+//! [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/getting-long-data
+use super::*;
+use std::cmp::min;
+use std::fmt;
+use std::io::{self, Read};
+
+/// Size of the internal buffer used if the buffer passed to `read` is too small to hold at least
+/// one character and the terminating zero.
+const SCRATCH_SIZE: usize = 64;
+
+impl<'con, 'param, 'col, A, CT> Statement<'con, 'param, 'col, Positioned, A, CT> {
+    /// Returns a reader streaming the value of a single column as `target_type` using successive
+    /// calls to `SQLGetData`.
+    ///
+    /// The terminating zero the driver appends to character data (`SQL_C_CHAR` and `SQL_C_WCHAR`)
+    /// is not part of the stream. NULL values are read as an empty stream. Use
+    /// `DataReader::indicator` to tell them apart from empty values.
+    ///
+    /// See [SQLGetData][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlgetdata-function
+    pub fn get_data_reader(
+        &mut self,
+        col_or_param_num: SQLUSMALLINT,
+        target_type: SqlCDataType,
+    ) -> DataReader<'_, 'con> {
+        let terminator_len = match target_type {
+            SQL_C_CHAR => 1,
+            SQL_C_WCHAR => 2,
+            _ => 0,
+        };
+        DataReader {
+            handle: &mut self.handle,
+            col_or_param_num,
+            target_type,
+            terminator_len,
+            indicator: None,
+            exhausted: false,
+            warning: false,
+            scratch: [0; SCRATCH_SIZE],
+            scratch_pos: 0,
+            scratch_end: 0,
+        }
+    }
+}
+
+/// Streams the value of a single column. Implements `std::io::Read`.
+///
+/// Created by `Statement::get_data_reader`.
+pub struct DataReader<'s, 'con: 's> {
+    handle: &'s mut HStmt<'con>,
+    col_or_param_num: SQLUSMALLINT,
+    target_type: SqlCDataType,
+    /// Length of the terminating zero appended by the driver in bytes.
+    terminator_len: usize,
+    indicator: Option<Indicator>,
+    exhausted: bool,
+    /// Final chunk has been returned with `SQL_SUCCESS_WITH_INFO`.
+    warning: bool,
+    scratch: [u8; SCRATCH_SIZE],
+    scratch_pos: usize,
+    scratch_end: usize,
+}
+
+impl<'s, 'con> DataReader<'s, 'con> {
+    /// Indicator returned by the most recent call to `SQLGetData`. `None` before the first call.
+    ///
+    /// `Some(Indicator::Null)` if the value is NULL. Otherwise the number of bytes remaining
+    /// before the most recent call (excluding the terminating zero) or `Indicator::NoTotal`.
+    pub fn indicator(&self) -> Option<Indicator> {
+        self.indicator
+    }
+
+    /// `true` if the driver returned the final chunk with `SQL_SUCCESS_WITH_INFO`. Truncation of
+    /// the preceding chunks does not count. The warnings can be read using `diagnostics`, as long
+    /// as `read` is not called again.
+    pub fn has_warning(&self) -> bool {
+        self.warning
+    }
+
+    /// Length of the data in the final chunk, if the driver does not report it. Character data is
+    /// terminated by zero, so it is searched for. Binary data is assumed to fill the buffer.
+    fn length_of_last_chunk(&self, chunk: &[u8], capacity: usize) -> usize {
+        match self.terminator_len {
+            1 => chunk.iter().position(|&b| b == 0),
+            2 => chunk.chunks(2).position(|c| c == [0, 0]).map(|chars| chars * 2),
+            _ => None,
+        }.map_or(capacity, |length| min(length, capacity))
+    }
+
+    /// Fetches the next chunk into `buf`. Returns the number of bytes of data written.
+    fn read_chunk(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        // Only whole wide characters fit into the buffer
+        let usable = if self.terminator_len == 2 {
+            buf.len() & !1
+        } else {
+            buf.len()
+        };
+        let capacity = usable - self.terminator_len;
+        match self.handle.get_data_chunk(
+            self.col_or_param_num,
+            self.target_type,
+            &mut buf[..usable],
+        ) {
+            ReturnOption::Success(indicator) => {
+                self.indicator = Some(indicator);
+                self.exhausted = true;
+                Ok(match indicator {
+                    Indicator::Length(length) => min(length as usize, capacity),
+                    Indicator::NoTotal => self.length_of_last_chunk(&buf[..usable], capacity),
+                    Indicator::Null => 0,
+                })
+            }
+            // Data has been truncated (01004), or the last chunk came with a warning
+            ReturnOption::Info(indicator) => {
+                self.indicator = Some(indicator);
+                Ok(match indicator {
+                    Indicator::Length(length) if length as usize <= capacity => {
+                        self.exhausted = true;
+                        self.warning = true;
+                        length as usize
+                    }
+                    Indicator::Length(_) | Indicator::NoTotal => capacity,
+                    Indicator::Null => {
+                        self.exhausted = true;
+                        self.warning = true;
+                        0
+                    }
+                })
+            }
+            ReturnOption::NoData(()) => {
+                self.exhausted = true;
+                Ok(0)
+            }
+            ReturnOption::Error(()) => Err(io::Error::other(
+                "SQLGetData returned SQL_ERROR. See diagnostics for details.",
+            )),
+        }
+    }
+}
+
+impl<'s, 'con> Read for DataReader<'s, 'con> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.scratch_pos == self.scratch_end {
+            if self.exhausted || buf.is_empty() {
+                return Ok(0);
+            }
+            if buf.len() >= SCRATCH_SIZE {
+                return self.read_chunk(buf);
+            }
+            let mut scratch = self.scratch;
+            self.scratch_end = self.read_chunk(&mut scratch)?;
+            self.scratch = scratch;
+            self.scratch_pos = 0;
+        }
+        let n = min(buf.len(), self.scratch_end - self.scratch_pos);
+        buf[..n].copy_from_slice(&self.scratch[self.scratch_pos..self.scratch_pos + n]);
+        self.scratch_pos += n;
+        Ok(n)
+    }
+}
+
+impl<'s, 'con> Diagnostics for DataReader<'s, 'con> {
+    fn diagnostics(
+        &self,
+        rec_number: SQLSMALLINT,
+        message_text: &mut [SQLCHAR],
+    ) -> ReturnOption<DiagResult> {
+        self.handle.diagnostics(rec_number, message_text)
+    }
+}
+
+impl<'s, 'con> fmt::Debug for DataReader<'s, 'con> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DataReader")
+            .field("col_or_param_num", &self.col_or_param_num)
+            .field("target_type", &self.target_type)
+            .field("indicator", &self.indicator)
+            .field("exhausted", &self.exhausted)
+            .field("warning", &self.warning)
+            .finish()
+    }
+}
@@ -0,0 +1,91 @@
+//! Accessing the descriptors of a statement and replacing its application descriptors with
+//! explicitly allocated ones.
+//!
+//! See [Descriptors][1]
+//! [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/descriptors
+use super::*;
+use ffi::{SQL_ATTR_APP_PARAM_DESC, SQL_ATTR_APP_ROW_DESC, SQL_ATTR_IMP_PARAM_DESC,
+          SQL_ATTR_IMP_ROW_DESC};
+
+impl<'con, 'param, 'col, S, A, CT> Statement<'con, 'param, 'col, S, A, CT> {
+    /// Application row descriptor (ARD) currently associated with the statement. Describes the
+    /// buffers bound to the columns of the result set.
+    pub fn app_row_desc(&self) -> Return<Descriptor<'con, '_, Implicit>> {
+        self.descriptor(SQL_ATTR_APP_ROW_DESC)
+    }
+
+    /// Application parameter descriptor (APD) currently associated with the statement. Describes
+    /// the buffers bound to the parameters.
+    pub fn app_param_desc(&self) -> Return<Descriptor<'con, '_, Implicit>> {
+        self.descriptor(SQL_ATTR_APP_PARAM_DESC)
+    }
+
+    /// Implementation row descriptor (IRD) of the statement. Describes the columns of the result
+    /// set.
+    pub fn imp_row_desc(&self) -> Return<Descriptor<'con, '_, Implicit>> {
+        self.descriptor(SQL_ATTR_IMP_ROW_DESC)
+    }
+
+    /// Implementation parameter descriptor (IPD) of the statement. Describes the parameters.
+    pub fn imp_param_desc(&self) -> Return<Descriptor<'con, '_, Implicit>> {
+        self.descriptor(SQL_ATTR_IMP_PARAM_DESC)
+    }
+
+    /// Uses the buffers bound to `desc` for the columns of the result set, instead of the ones
+    /// bound with e.g. `bind_col`. Use `reset_columns` to revert to the implicit descriptor.
+    ///
+    /// # Result
+    /// This method will destroy the statement and create a new one which may not outlive `desc`.
+    ///
+    /// # Safety
+    /// While `desc` is set, functions binding columns like `bind_col` write the new buffers into
+    /// `desc`, which is not bound to their lifetime. `desc` must not be used after any of these
+    /// buffers have been freed, e.g. by setting it on another statement.
+    pub unsafe fn set_app_row_desc<'d, 'buf>(
+        mut self,
+        desc: &'d Descriptor<'con, 'buf>,
+    ) -> Return<Statement<'con, 'param, 'd, S, A, CT>, Self>
+    where
+        'col: 'd,
+        'buf: 'd,
+    {
+        let raw = desc.as_raw() as SQLPOINTER;
+        match self.handle.set_attribute(SQL_ATTR_APP_ROW_DESC, raw) {
+            Success(()) => Success(self.transit()),
+            Info(()) => Info(self.transit()),
+            Error(()) => Error(self),
+        }
+    }
+
+    /// Uses the buffers bound to `desc` for the parameters, instead of the ones bound with e.g.
+    /// `bind_input_parameter`. Use `reset_parameters` to revert to the implicit descriptor.
+    ///
+    /// # Result
+    /// This method will destroy the statement and create a new one which may not outlive `desc`.
+    ///
+    /// # Safety
+    /// While `desc` is set, functions binding parameters like `bind_input_parameter` write the new
+    /// buffers into `desc`, which is not bound to their lifetime. `desc` must not be used after
+    /// any of these buffers have been freed, e.g. by setting it on another statement.
+    pub unsafe fn set_app_param_desc<'d, 'buf>(
+        mut self,
+        desc: &'d Descriptor<'con, 'buf>,
+    ) -> Return<Statement<'con, 'd, 'col, S, A, CT>, Self>
+    where
+        'param: 'd,
+        'buf: 'd,
+    {
+        let raw = desc.as_raw() as SQLPOINTER;
+        match self.handle.set_attribute(SQL_ATTR_APP_PARAM_DESC, raw) {
+            Success(()) => Success(self.transit()),
+            Info(()) => Info(self.transit()),
+            Error(()) => Error(self),
+        }
+    }
+
+    fn descriptor(&self, attribute: SQLINTEGER) -> Return<Descriptor<'con, '_, Implicit>> {
+        self.handle
+            .descriptor(attribute)
+            .map(|raw| unsafe { Descriptor::from_raw_implicit(raw) })
+    }
+}
@@ -0,0 +1,126 @@
+//! `Future`s driving asynchronously executing statements to completion, independent of any async
+//! runtime.
+use super::*;
+use backoff::Backoff;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use timer::wake_after;
+
+/// Result of a function executed asynchronously, which enters the cursor state `S` on success
+type AsyncResult<'con, 'param, 'col, S, A, CT> = ReturnAsync<
+    Statement<'con, 'param, 'col, S, A, CT>,
+    Statement<'con, 'param, 'col, Executing<S>, A, CT>,
+    Statement<'con, 'param, 'col, NoCursor, A, CT>,
+>;
+
+/// Completes once the function executing asynchronously on the `Statement` is complete. Resolves
+/// to the same result as the blocking variant of the function.
+///
+/// While the function is still executing, the driver is polled again after a delay controlled by
+/// `Backoff`. Dropping the future before it is complete cancels the execution. The drop blocks the
+/// calling thread until the driver has acknowledged the cancellation, sleeping between two polls.
+///
+/// Like a statement in the `Executing` state, the future must never be leaked. Therefore the
+/// functions creating it are `unsafe`.
+#[derive(Debug)]
+#[must_use = "dropping the future cancels the execution"]
+pub struct StatementFuture<'con, 'param, 'col, S, A, CT = ForwardOnly> {
+    state: Option<AsyncResult<'con, 'param, 'col, S, A, CT>>,
+    backoff: Backoff,
+    delay: Duration,
+}
+
+impl<'con, 'param, 'col, S, A, CT> StatementFuture<'con, 'param, 'col, S, A, CT> {
+    fn new(state: AsyncResult<'con, 'param, 'col, S, A, CT>) -> Self {
+        let backoff = Backoff::default();
+        StatementFuture {
+            state: Some(state),
+            backoff,
+            delay: backoff.initial,
+        }
+    }
+
+    /// Changes how often the driver is polled, while the function is still executing
+    pub fn with_backoff(mut self, backoff: Backoff) -> Self {
+        self.backoff = backoff;
+        self.delay = backoff.initial;
+        self
+    }
+}
+
+// The future never relies on being pinned
+impl<'con, 'param, 'col, S, A, CT> Unpin for StatementFuture<'con, 'param, 'col, S, A, CT> {}
+
+impl<'con, 'param, 'col, S, A, CT> Future for StatementFuture<'con, 'param, 'col, S, A, CT> {
+    type Output = ReturnOption<
+        Statement<'con, 'param, 'col, S, A, CT>,
+        Statement<'con, 'param, 'col, NoCursor, A, CT>,
+    >;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let state = match this.state.take().expect("Future polled after completion") {
+            ReturnAsync::StillExecuting(statement) => statement.poll(),
+            complete => complete,
+        };
+        match state {
+            ReturnAsync::Success(s) => Poll::Ready(ReturnOption::Success(s)),
+            ReturnAsync::Info(s) => Poll::Ready(ReturnOption::Info(s)),
+            ReturnAsync::StillExecuting(s) => {
+                this.state = Some(ReturnAsync::StillExecuting(s));
+                wake_after(this.delay, cx.waker().clone());
+                this.delay = this.backoff.next(this.delay);
+                Poll::Pending
+            }
+            ReturnAsync::NoData(s) => Poll::Ready(ReturnOption::NoData(s)),
+            ReturnAsync::Error(s) => Poll::Ready(ReturnOption::Error(s)),
+        }
+    }
+}
+
+impl<'con, 'param, 'col, CT> Statement<'con, 'param, 'col, NoCursor, Unprepared, CT> {
+    /// Executes the statement text asynchronously. Execution starts immediately, the returned
+    /// future drives it to completion. Asynchronous execution must be enabled using
+    /// `set_async_enable`, otherwise execution blocks until it is complete.
+    ///
+    /// # Safety
+    /// The returned future must not be leaked before it is complete. See `Executing`.
+    pub unsafe fn exec_direct_future<T>(
+        self,
+        statement_text: &T,
+    ) -> StatementFuture<'con, 'param, 'col, Open, Unprepared, CT>
+    where
+        T: SqlStr + ?Sized,
+    {
+        StatementFuture::new(self.exec_direct_async(statement_text))
+    }
+}
+
+impl<'con, 'param, 'col, CT> Statement<'con, 'param, 'col, NoCursor, Prepared, CT> {
+    /// Executes the prepared statement asynchronously. Execution starts immediately, the returned
+    /// future drives it to completion. Asynchronous execution must be enabled using
+    /// `set_async_enable`, otherwise execution blocks until it is complete.
+    ///
+    /// # Safety
+    /// The returned future must not be leaked before it is complete. See `Executing`.
+    pub unsafe fn execute_future(self) -> StatementFuture<'con, 'param, 'col, Open, Prepared, CT> {
+        StatementFuture::new(self.execute_async())
+    }
+}
+
+impl<'con, 'param, 'col, C, A, CT> Statement<'con, 'param, 'col, C, A, CT>
+where
+    C: CursorState,
+{
+    /// Fetches the next row set asynchronously. The fetch starts immediately, the returned future
+    /// drives it to completion. Asynchronous execution must be enabled using `set_async_enable`,
+    /// otherwise fetching blocks until it is complete.
+    ///
+    /// # Safety
+    /// The returned future must not be leaked before it is complete. See `Executing`.
+    pub unsafe fn fetch_future(self) -> StatementFuture<'con, 'param, 'col, Positioned, A, CT> {
+        StatementFuture::new(self.fetch_async())
+    }
+}
@@ -0,0 +1,1110 @@
+pub use self::col_attribute::DescribesColumns;
+pub use self::data_at_exec::ParamWriter;
+pub use self::data_reader::DataReader;
+pub use self::future::StatementFuture;
+use super::*;
+use sys::*;
+use std::marker::PhantomData;
+use std::mem::{size_of, size_of_val};
+use std::ptr::{null, null_mut};
+use std::time::Duration;
+
+mod catalog;
+mod col_attribute;
+mod data_at_exec;
+mod data_reader;
+mod descriptors;
+mod future;
+mod polling;
+mod scroll;
+mod set_pos;
+
+/// A `Statement` is most easily thought of as an SQL statement, such as
+/// `SELECT * FROM Employee`.
+///
+/// * The statement's state
+/// * The current statement-level diagnostics
+/// * The addresses of the application variables bound to the statement's
+/// parameters and result set
+///   columns
+/// * The current settings of each statement attribute
+///
+/// See [Statement Handles][1]
+///
+/// Specific to the rust wrapper of an ODBC Statement is, that we do keep track
+/// of the lifetimes of
+/// the parent `Connection`, parameters as well as `columns` bound to the
+/// `Statement`. Since it is
+/// possible to unbind the parameters and columns we have to keep track of
+/// their lifetimes
+/// seperatly.
+///
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/statement-handles
+#[derive(Debug)]
+pub struct Statement<'con, 'param, 'col, C = NoCursor, A = Unprepared, CT = ForwardOnly> {
+    cursor: PhantomData<C>,
+    access_plan: PhantomData<A>,
+    cursor_type: PhantomData<CT>,
+    /// Statement may not outlive parameters bound to it.
+    parameters: PhantomData<&'param [u8]>,
+    columns: PhantomData<&'col [u8]>,
+    handle: HStmt<'con>,
+}
+
+/// Cursor state of `Statement`. A statement is likely to enter this state
+/// after executing e.g a
+/// `SELECT` query.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)]
+pub enum Open {}
+/// State used by `Statement`. A statement is likely to enter this state after
+/// executing e.g. a
+/// `CREATE TABLE` statement.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)]
+pub enum NoCursor {}
+/// Cursor state of `Statement`. A statement will enter this state after a
+/// successful call to
+/// `fetch()`
+#[derive(Debug)]
+#[allow(missing_copy_implementations)]
+pub enum Positioned {}
+/// State used by `Statement`. A statement will enter this state after a
+/// successful call to
+/// `prepare()`.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)]
+pub enum Prepared {}
+/// State used by `Statement`. Indicates that no Access Plan has been created,
+/// yet.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)]
+pub enum Unprepared {}
+/// State used by `Statement`. A statement enters this state if it has been executed with
+/// parameters bound as data-at-execution. Data for these parameters is sent using `param_data`.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)]
+pub enum NeedData {}
+/// State used by `Statement`. A function of the statement is still executing asynchronously. Use
+/// `poll` to drive it to completion, after which the statement enters the state `S`.
+///
+/// Dropping an executing statement cancels the function and waits for it to complete, since the
+/// driver may still read from or write to the bound buffers until then. Leaking an executing
+/// statement instead (e.g. using `std::mem::forget`) skips this, so the driver may access the
+/// buffers after they have been freed. Since leaking is safe, the functions entering this state
+/// are `unsafe`. Callers must ensure that a statement in this state is never leaked.
+#[derive(Debug)]
+pub struct Executing<S>(PhantomData<S>);
+/// Cursor type of `Statement`. The default. The cursor only moves forward, one row set at a time.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)]
+pub enum ForwardOnly {}
+/// Cursor type of `Statement`. The statement has been configured to create scrollable cursors,
+/// which can be moved to arbitrary positions using `fetch_scroll`.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)]
+pub enum Scrollable {}
+
+/// Implemented by the `Open` and `Positioned` states for `Statement`.
+pub trait CursorState {}
+impl CursorState for Open {}
+impl CursorState for Positioned {}
+
+impl<'con, 'param, 'col, S, A, CT> Statement<'con, 'param, 'col, S, A, CT> {
+    /// Provides access to the raw ODBC Statement Handle
+    pub fn as_raw(&self) -> SQLHSTMT {
+        self.handle.as_raw()
+    }
+
+    /// Returns a handle which can be sent to another thread in order to cancel the execution of
+    /// this statement. Execution canceled this way fails with a diagnostic record of kind
+    /// `ErrorKind::Canceled`.
+    pub fn cancel_handle(&mut self) -> CancelHandle<'con> {
+        self.handle.cancel_handle()
+    }
+
+    /// Binds a parameter to a parameter marker in an SQL Statement
+    ///
+    /// # Result
+    /// This method will destroy the statement and create a new one which may not outlive the bound
+    /// parameter. This is to ensure that the statement will not derefernce an invalid pointer
+    /// during execution. Use `reset_parameters` to reset the bound parameters and increase the
+    /// `'param` lifetime back to `'static`. If the parameter set size of the statement can not be
+    /// determined, the statement is returned unchanged as `Error`.
+    ///
+    /// # Arguments
+    /// * `parameter_number` - Index of the marker to bind to the parameter. Starting at `1`
+    /// * `parameter_type` - SQL Type of the parameter
+    /// * `value` - Reference to bind to the marker
+    ///
+    /// # Panics
+    /// If the statement has been configured to execute with more than one set of parameters using
+    /// `bind_param_set`. Use `bind_input_parameter_array` instead.
+    ///
+    /// See [SQLBindParameter Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlbindparameter-function#columnsize-argument
+    pub fn bind_input_parameter<'p, T>(
+        mut self,
+        parameter_number: SQLUSMALLINT,
+        parameter_type: DataType,
+        value: &'p T,
+        indicator: Option<&'p SQLLEN>,
+    ) -> Return<Statement<'con, 'p, 'col, S, A, CT>, Self>
+    where
+        T: CDataType + ?Sized,
+        'param: 'p,
+    {
+        match self.handle.paramset_size() {
+            Success(paramset_size) | Info(paramset_size) => assert_eq!(
+                paramset_size,
+                1,
+                "Parameters bound with `bind_input_parameter` can only hold a single value."
+            ),
+            Error(()) => return Error(self),
+        }
+        unsafe {
+            match self.handle.bind_input_parameter(
+                parameter_number,
+                parameter_type,
+                value,
+                indicator,
+            ) {
+                Success(()) => Success(self.transit()),
+                Info(()) => Info(self.transit()),
+                Error(()) => Error(self.transit()),
+            }
+        }
+    }
+
+    /// Binds a buffer to an output parameter marker, e.g. the return value in
+    /// `{? = call proc(?)}`.
+    ///
+    /// The driver writes the value and its length (or `SQL_NULL_DATA`) to `value` and `indicator`.
+    /// Output parameters are only available once all result sets generated by the statement have
+    /// been processed. Use `reset_parameters` to release the buffers in order to read them.
+    ///
+    /// # Panics
+    /// If the statement has been configured to execute with more than one set of parameters using
+    /// `bind_param_set`.
+    ///
+    /// See [SQLBindParameter Function][1]
+    /// See [Procedure Parameters][2]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlbindparameter-function
+    /// [2]: https://docs.microsoft.com/sql/odbc/reference/develop-app/procedure-parameters
+    pub fn bind_output_parameter<'p, T>(
+        self,
+        parameter_number: SQLUSMALLINT,
+        parameter_type: DataType,
+        value: &'p mut T,
+        indicator: &'p mut SQLLEN,
+    ) -> Return<Statement<'con, 'p, 'col, S, A, CT>, Self>
+    where
+        T: CDataType + ?Sized,
+        'param: 'p,
+    {
+        self.bind_mut_parameter(
+            parameter_number,
+            SQL_PARAM_OUTPUT,
+            parameter_type,
+            value,
+            indicator,
+        )
+    }
+
+    /// Binds a buffer to an input/output parameter marker.
+    ///
+    /// `value` and `indicator` are read by the driver during execution and overwritten with the
+    /// value returned by the data source. Output parameters are only available once all result
+    /// sets generated by the statement have been processed. Use `reset_parameters` to release the
+    /// buffers in order to read them.
+    ///
+    /// # Panics
+    /// If the statement has been configured to execute with more than one set of parameters using
+    /// `bind_param_set`, or if `indicator` is larger than the buffer length of `value`.
+    ///
+    /// See [SQLBindParameter Function][1]
+    /// See [Procedure Parameters][2]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlbindparameter-function
+    /// [2]: https://docs.microsoft.com/sql/odbc/reference/develop-app/procedure-parameters
+    pub fn bind_input_output_parameter<'p, T>(
+        self,
+        parameter_number: SQLUSMALLINT,
+        parameter_type: DataType,
+        value: &'p mut T,
+        indicator: &'p mut SQLLEN,
+    ) -> Return<Statement<'con, 'p, 'col, S, A, CT>, Self>
+    where
+        T: CDataType + ?Sized,
+        'param: 'p,
+    {
+        assert!(
+            *indicator <= value.buffer_len(),
+            "Indicator cannot be larger than buffer length."
+        );
+        self.bind_mut_parameter(
+            parameter_number,
+            SQL_PARAM_INPUT_OUTPUT,
+            parameter_type,
+            value,
+            indicator,
+        )
+    }
+
+    fn bind_mut_parameter<'p, T>(
+        mut self,
+        parameter_number: SQLUSMALLINT,
+        input_output_type: InputOutput,
+        parameter_type: DataType,
+        value: &'p mut T,
+        indicator: &'p mut SQLLEN,
+    ) -> Return<Statement<'con, 'p, 'col, S, A, CT>, Self>
+    where
+        T: CDataType + ?Sized,
+        'param: 'p,
+    {
+        match self.handle.paramset_size() {
+            Success(paramset_size) | Info(paramset_size) => assert_eq!(
+                paramset_size,
+                1,
+                "Output parameters can only hold a single value."
+            ),
+            Error(()) => return Error(self),
+        }
+        unsafe {
+            match self.handle.bind_output_parameter(
+                parameter_number,
+                input_output_type,
+                parameter_type,
+                value,
+                indicator,
+            ) {
+                Success(()) => Success(self.transit()),
+                Info(()) => Info(self.transit()),
+                Error(()) => Error(self.transit()),
+            }
+        }
+    }
+
+    /// Binds a buffer and an indicator to a column.
+    ///
+    /// # Result
+    /// If the row array size of the statement can not be determined, the statement is returned
+    /// unchanged as `Error`.
+    ///
+    /// # Panics
+    /// If the statement has been configured to fetch more than one row at once using
+    /// `bind_row_set`. Use `bind_col_array` or `bind_row_col` instead.
+    ///
+    /// See [SQLBindCol][1]:
+    /// [1]: [https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlbindcol-function]
+    pub fn bind_col<'col_new, T>(
+        mut self,
+        column_number: SQLUSMALLINT,
+        value: &'col_new mut T,
+        indicator: Option<&'col_new mut SQLLEN>,
+    ) -> Return<Statement<'con, 'param, 'col_new, S, A, CT>, Self>
+    where
+        T: CDataType + ?Sized,
+        'col: 'col_new,
+    {
+        match self.handle.row_array_size() {
+            Success(row_array_size) | Info(row_array_size) => assert_eq!(
+                row_array_size,
+                1,
+                "Buffers bound with `bind_col` can only hold a single row."
+            ),
+            Error(()) => return Error(self),
+        }
+        unsafe {
+            match self.handle.bind_col(column_number, value, indicator) {
+                Success(()) => Success(self.transit()),
+                Info(()) => Info(self.transit()),
+                Error(()) => Error(self.transit()),
+            }
+        }
+    }
+
+    /// Configures the statement to fetch `row_status.len()` rows with each call to `fetch`.
+    ///
+    /// Columns bound previously are unbound, since their buffers only hold a single row. Bind
+    /// arrays to the columns afterwards using `bind_col_array` (for `BindType::Column`) or
+    /// `bind_row_col` (for `BindType::Row`). After each call to `fetch` the driver writes the
+    /// number of rows fetched to `rows_fetched` and the status of each row to `row_status`. Both
+    /// can be inspected using the methods of the same name on the positioned statement.
+    ///
+    /// The statement returned in case of an error may have been partially configured.
+    ///
+    /// # Panics
+    /// If `row_status` is empty.
+    ///
+    /// See [Block Cursors][1]
+    /// See [Row-Wise Binding][2]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/block-cursors
+    /// [2]: https://docs.microsoft.com/sql/odbc/reference/develop-app/row-wise-binding
+    pub fn bind_row_set<'col_new>(
+        self,
+        bind_type: BindType,
+        rows_fetched: &'col_new mut SQLULEN,
+        row_status: &'col_new mut [SQLUSMALLINT],
+    ) -> Return<Statement<'con, 'param, 'col_new, S, A, CT>, Statement<'con, 'param, 'col_new, S, A, CT>>
+    {
+        assert!(!row_status.is_empty(), "Row set must hold at least one row.");
+        let mut stmt = self.reset_columns();
+        unsafe {
+            match stmt.handle.bind_row_set(bind_type.value(), rows_fetched, row_status) {
+                Success(()) => Success(stmt.transit()),
+                Info(()) => Info(stmt.transit()),
+                Error(()) => Error(stmt.transit()),
+            }
+        }
+    }
+
+    /// Binds an array of values and an array of indicators to a column of a block cursor using
+    /// column wise binding.
+    ///
+    /// # Panics
+    /// If the statement is not configured for column wise binding or if `values` or `indicators`
+    /// hold fewer elements than the row set.
+    ///
+    /// See [Column-Wise Binding][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/column-wise-binding
+    pub fn bind_col_array<'col_new, T>(
+        mut self,
+        column_number: SQLUSMALLINT,
+        values: &'col_new mut [T],
+        indicators: Option<&'col_new mut [SQLLEN]>,
+    ) -> Return<Statement<'con, 'param, 'col_new, S, A, CT>, Self>
+    where
+        T: CDataType,
+        'col: 'col_new,
+    {
+        let row_array_size = match self.column_wise_row_array_size() {
+            Success(row_array_size) | Info(row_array_size) => row_array_size,
+            Error(()) => return Error(self),
+        };
+        assert!(
+            values.len() >= row_array_size,
+            "Values must hold an element for each row in the row set."
+        );
+        let indicator = indicators.map(|indicators| {
+            assert!(
+                indicators.len() >= row_array_size,
+                "Indicators must hold an element for each row in the row set."
+            );
+            &mut indicators[0]
+        });
+        unsafe {
+            match self.handle.bind_col(column_number, &mut values[0], indicator) {
+                Success(()) => Success(self.transit()),
+                Info(()) => Info(self.transit()),
+                Error(()) => Error(self.transit()),
+            }
+        }
+    }
+
+    /// Binds a buffer holding variable sized elements of `element_length` bytes and an array of
+    /// indicators to a column of a block cursor using column wise binding.
+    ///
+    /// # Panics
+    /// If the statement is not configured for column wise binding or if `values` or `indicators`
+    /// are too small to hold an element for each row of the row set.
+    ///
+    /// See [Column-Wise Binding][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/column-wise-binding
+    pub fn bind_binary_col_array<'col_new>(
+        mut self,
+        column_number: SQLUSMALLINT,
+        values: &'col_new mut [u8],
+        element_length: usize,
+        indicators: Option<&'col_new mut [SQLLEN]>,
+    ) -> Return<Statement<'con, 'param, 'col_new, S, A, CT>, Self>
+    where
+        'col: 'col_new,
+    {
+        let row_array_size = match self.column_wise_row_array_size() {
+            Success(row_array_size) | Info(row_array_size) => row_array_size,
+            Error(()) => return Error(self),
+        };
+        assert!(element_length != 0, "Element length must not be zero.");
+        assert!(
+            values.len() / element_length >= row_array_size,
+            "Values must hold an element for each row in the row set."
+        );
+        let indicator = indicators.map(|indicators| {
+            assert!(
+                indicators.len() >= row_array_size,
+                "Indicators must hold an element for each row in the row set."
+            );
+            &mut indicators[0]
+        });
+        unsafe {
+            match self.handle.bind_col(column_number, &mut values[..element_length], indicator) {
+                Success(()) => Success(self.transit()),
+                Info(()) => Info(self.transit()),
+                Error(()) => Error(self.transit()),
+            }
+        }
+    }
+
+    /// Binds a field of each row in `rows` and optionally a field holding its indicator to a
+    /// column of a block cursor using row wise binding.
+    ///
+    /// `value` and `indicator` select the field from a row, e.g. `|row| &mut row.title[..]`.
+    ///
+    /// # Panics
+    /// If the statement is not configured for row wise binding of rows of type `R`, if `rows`
+    /// holds fewer elements than the row set or if a selected field does not lie within the row.
+    ///
+    /// See [Row-Wise Binding][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/row-wise-binding
+    pub fn bind_row_col<'col_new, R, T>(
+        mut self,
+        column_number: SQLUSMALLINT,
+        rows: &'col_new mut [R],
+        value: fn(&mut R) -> &mut T,
+        indicator: Option<fn(&mut R) -> &mut SQLLEN>,
+    ) -> Return<Statement<'con, 'param, 'col_new, S, A, CT>, Self>
+    where
+        T: CDataType + ?Sized,
+        'col: 'col_new,
+    {
+        match self.handle.row_bind_type() {
+            Success(bind_type) | Info(bind_type) => assert_eq!(
+                bind_type,
+                BindType::Row(size_of::<R>()).value(),
+                "Statement must be configured for row wise binding of this row type."
+            ),
+            Error(()) => return Error(self),
+        }
+        let row_array_size = match self.handle.row_array_size() {
+            Success(row_array_size) | Info(row_array_size) => row_array_size as usize,
+            Error(()) => return Error(self),
+        };
+        assert!(
+            rows.len() >= row_array_size,
+            "Rows must hold an element for each row in the row set."
+        );
+        let row: *mut R = &mut rows[0];
+        let (value_ptr, buffer_length) = {
+            let value = value(&mut rows[0]);
+            assert_within_row(row, value);
+            (value.mut_sql_ptr(), value.buffer_len())
+        };
+        let indicator_ptr: *mut SQLLEN = match indicator {
+            Some(indicator) => {
+                let indicator = indicator(&mut rows[0]);
+                assert_within_row(row, indicator);
+                indicator
+            }
+            None => null_mut(),
+        };
+        unsafe {
+            match self.handle.bind_col_ptr(
+                column_number,
+                T::c_data_type(),
+                value_ptr,
+                buffer_length,
+                indicator_ptr,
+            ) {
+                Success(()) => Success(self.transit()),
+                Info(()) => Info(self.transit()),
+                Error(()) => Error(self.transit()),
+            }
+        }
+    }
+
+    /// Configures the statement to be executed once for each of `param_status.len()` sets of
+    /// parameters.
+    ///
+    /// Parameters bound previously are unbound, since their buffers only hold a single value. Bind
+    /// arrays to the parameter markers afterwards using `bind_input_parameter_array` or
+    /// `bind_binary_input_parameter_array`. After each execution the driver writes the number of
+    /// parameter sets processed to `params_processed` and the status of each set to
+    /// `param_status`. Both can be inspected using the methods of the same name.
+    ///
+    /// The statement returned in case of an error may have been partially configured.
+    ///
+    /// # Panics
+    /// If `param_status` is empty.
+    ///
+    /// See [Arrays of Parameter Values][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/arrays-of-parameter-values
+    pub fn bind_param_set<'p>(
+        self,
+        params_processed: &'p mut SQLULEN,
+        param_status: &'p mut [SQLUSMALLINT],
+    ) -> Return<Statement<'con, 'p, 'col, S, A, CT>, Statement<'con, 'p, 'col, S, A, CT>> {
+        assert!(!param_status.is_empty(), "Parameter set must hold at least one row.");
+        let mut stmt = self.reset_parameters();
+        unsafe {
+            match stmt.handle.bind_param_set(params_processed, param_status) {
+                Success(()) => Success(stmt.transit()),
+                Info(()) => Info(stmt.transit()),
+                Error(()) => Error(stmt.transit()),
+            }
+        }
+    }
+
+    /// Binds an array of values and an array of indicators to a parameter marker using column
+    /// wise binding.
+    ///
+    /// # Panics
+    /// If `values` or `indicators` hold fewer elements than the parameter set, or if an indicator
+    /// is larger than the size of a single value.
+    ///
+    /// See [Binding Arrays of Parameters][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/binding-arrays-of-parameters
+    pub fn bind_input_parameter_array<'p, T>(
+        mut self,
+        parameter_number: SQLUSMALLINT,
+        parameter_type: DataType,
+        values: &'p [T],
+        indicators: Option<&'p [SQLLEN]>,
+    ) -> Return<Statement<'con, 'p, 'col, S, A, CT>, Self>
+    where
+        T: CDataType,
+        'param: 'p,
+    {
+        let paramset_size = match self.handle.paramset_size() {
+            Success(paramset_size) | Info(paramset_size) => paramset_size as usize,
+            Error(()) => return Error(self),
+        };
+        assert!(
+            values.len() >= paramset_size,
+            "Values must hold an element for each set of parameters."
+        );
+        let element_length = size_of::<T>() as SQLLEN;
+        let indicators = checked_indicators(indicators, paramset_size, element_length);
+        unsafe {
+            match self.handle.bind_parameter_ptr(
+                parameter_number,
+                SQL_PARAM_INPUT,
+                T::c_data_type(),
+                parameter_type,
+                values.as_ptr() as SQLPOINTER,
+                element_length,
+                indicators as *mut SQLLEN,
+            ) {
+                Success(()) => Success(self.transit()),
+                Info(()) => Info(self.transit()),
+                Error(()) => Error(self.transit()),
+            }
+        }
+    }
+
+    /// Binds a buffer holding variable sized elements of `element_length` bytes and an array of
+    /// indicators to a parameter marker using column wise binding. The indicators hold the length
+    /// of each element.
+    ///
+    /// # Panics
+    /// If `values` or `indicators` are too small to hold an element for each set of parameters,
+    /// or if an indicator is larger than `element_length`.
+    ///
+    /// See [Binding Arrays of Parameters][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/binding-arrays-of-parameters
+    pub fn bind_binary_input_parameter_array<'p>(
+        mut self,
+        parameter_number: SQLUSMALLINT,
+        parameter_type: DataType,
+        values: &'p [u8],
+        element_length: usize,
+        indicators: Option<&'p [SQLLEN]>,
+    ) -> Return<Statement<'con, 'p, 'col, S, A, CT>, Self>
+    where
+        'param: 'p,
+    {
+        let paramset_size = match self.handle.paramset_size() {
+            Success(paramset_size) | Info(paramset_size) => paramset_size as usize,
+            Error(()) => return Error(self),
+        };
+        assert!(element_length != 0, "Element length must not be zero.");
+        assert!(
+            values.len() / element_length >= paramset_size,
+            "Values must hold an element for each set of parameters."
+        );
+        let indicators = checked_indicators(indicators, paramset_size, element_length as SQLLEN);
+        unsafe {
+            match self.handle.bind_parameter_ptr(
+                parameter_number,
+                SQL_PARAM_INPUT,
+                <[u8]>::c_data_type(),
+                parameter_type,
+                values.as_ptr() as SQLPOINTER,
+                element_length as SQLLEN,
+                indicators as *mut SQLLEN,
+            ) {
+                Success(()) => Success(self.transit()),
+                Info(()) => Info(self.transit()),
+                Error(()) => Error(self.transit()),
+            }
+        }
+    }
+
+    /// Number of parameter sets processed by the last execution with the parameter set
+    /// configured with `bind_param_set`. Always `1` if no parameter set has been configured.
+    pub fn params_processed(&self) -> Return<SQLULEN> {
+        // The buffer lives at least as long as 'param
+        unsafe { self.handle.params_processed().map(|processed| processed.unwrap_or(1)) }
+    }
+
+    /// Status of a set of parameters configured with `bind_param_set` after execution. `index`
+    /// starts at `0`. Always `ParamStatus::Success` if no parameter set has been configured.
+    ///
+    /// # Panics
+    /// If `index` is not smaller than the number of sets in the parameter set.
+    pub fn param_status(&self, index: usize) -> Return<ParamStatus> {
+        let paramset_size = match self.handle.paramset_size() {
+            Success(paramset_size) | Info(paramset_size) => paramset_size as usize,
+            Error(()) => return Error(()),
+        };
+        assert!(index < paramset_size, "Parameter set index out of bounds.");
+        // The buffer lives at least as long as 'param and holds `paramset_size` elements
+        unsafe {
+            self.handle
+                .param_status(index)
+                .map(|status| status.map_or(ParamStatus::Success, ParamStatus::from))
+        }
+    }
+
+    /// Unbinds the parameters from the parameter markers. Also releases the buffers bound by
+    /// `bind_param_set`, so the statement is executed with a single set of parameters again.
+    pub fn reset_parameters(mut self) -> Statement<'con, 'static, 'col, S, A, CT> {
+        self.handle.reset_parameters().unwrap();
+        self.handle.reset_param_set().unwrap();
+        self.transit()
+    }
+
+    /// Unbinds column buffers from result set. Also releases the buffers bound by `bind_row_set`,
+    /// so the statement fetches a single row at a time again.
+    pub fn reset_columns(mut self) -> Statement<'con, 'param, 'static, S, A, CT> {
+        self.handle.reset_columns().unwrap();
+        self.handle.reset_row_set().unwrap();
+        self.transit()
+    }
+
+    /// Shared implementation of `more_results` for cursor and no cursor states
+    fn next_result(
+        mut self,
+    ) -> ReturnOption<
+        ResultSet<'con, 'param, 'col, A, CT>,
+        Statement<'con, 'param, 'col, NoCursor, A, CT>,
+    > {
+        match self.handle.more_results() {
+            ReturnOption::Success(()) => ReturnOption::Success(self.transit()),
+            ReturnOption::Info(()) => ReturnOption::Info(self.transit()),
+            ReturnOption::NoData(()) => ReturnOption::NoData(self.transit()),
+            ReturnOption::Error(()) => ReturnOption::Error(self.transit()),
+        }
+    }
+
+    /// Row array size of a statement configured for column wise binding
+    fn column_wise_row_array_size(&self) -> Return<usize> {
+        match self.handle.row_bind_type() {
+            Success(bind_type) | Info(bind_type) => assert_eq!(
+                bind_type,
+                BindType::Column.value(),
+                "Statement must be configured for column wise binding."
+            ),
+            Error(()) => return Error(()),
+        }
+        self.handle.row_array_size().map(|row_array_size| row_array_size as usize)
+    }
+
+    fn transit<'p, 'c, S2, A2>(self) -> Statement<'con, 'p, 'c, S2, A2, CT> {
+        self.transit_cursor_type()
+    }
+
+    fn transit_cursor_type<'p, 'c, S2, A2, CT2>(self) -> Statement<'con, 'p, 'c, S2, A2, CT2> {
+        Statement {
+            handle: self.handle,
+            parameters: PhantomData,
+            columns: PhantomData,
+            cursor: PhantomData,
+            access_plan: PhantomData,
+            cursor_type: PhantomData,
+        }
+    }
+}
+
+impl<'con, 'param, 'col, C, A, CT> Statement<'con, 'param, 'col, C, A, CT>
+where
+    C: CursorState,
+{
+    /// Returns the number of columns of the result set
+    ///
+    /// See [SQLNumResultCols][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlnumresultcols-function
+    pub fn num_result_cols(&self) -> Return<SQLSMALLINT> {
+        self.handle.num_result_cols()
+    }
+
+    /// Returns the number of rows affetced by INSERT, UPDATE, etc
+    ///
+    /// See [SQLRowCount][1]
+    /// [1]: https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlrowcount-function
+    pub fn affected_row_count(&self) -> Return<SQLLEN> { self.handle.affected_row_count() }
+
+    /// Advances Cursor to next row
+    ///
+    /// See [SQLFetch][1]
+    /// See [Fetching a Row of Data][2]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlfetch-function
+    /// [2]: https://docs.microsoft.com/sql/odbc/reference/develop-app/fetching-a-row-of-data
+    pub fn fetch(
+        mut self,
+    ) -> ReturnOption<
+        Statement<'con, 'param, 'col, Positioned, A, CT>,
+        Statement<'con, 'param, 'col, NoCursor, A, CT>,
+    > {
+        match self.handle.fetch() {
+            ReturnOption::Success(()) => ReturnOption::Success(self.transit()),
+            ReturnOption::Info(()) => ReturnOption::Info(self.transit()),
+            ReturnOption::NoData(()) => ReturnOption::NoData(self.transit()),
+            ReturnOption::Error(()) => ReturnOption::Error(self.transit()),
+        }
+    }
+
+    /// Closes the cursor. Cursors only need to be closed explicitly if the
+    /// Statement handle is
+    /// intended to be reused, but a result set is not consumed. Prepared statements stay
+    /// prepared and can be executed again.
+    ///
+    /// See [SQLCloseCursor][1]
+    /// See [Closing the Cursor][2]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlclosecursor-function
+    /// [2]: https://docs.microsoft.com/sql/odbc/reference/develop-app/closing-the-cursor
+    pub fn close_cursor(
+        mut self,
+    ) -> Return<Statement<'con, 'param, 'col, NoCursor, A, CT>, Statement<'con, 'param, 'col, C, A, CT>> {
+        match self.handle.close_cursor() {
+            Success(()) => Success(self.transit()),
+            Info(()) => Info(self.transit()),
+            Error(()) => Error(self.transit()),
+        }
+    }
+
+    /// Moves on to the next result set generated by a batch of SQL statements or a stored
+    /// procedure. Returns `NoData` once all results have been processed. Statements which do not
+    /// return rows (e.g. an `UPDATE` within a batch) yield a result set without columns, whose
+    /// row count is available using `affected_row_count`.
+    ///
+    /// See [SQLMoreResults Function][1]
+    /// See [Multiple Results][2]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlmoreresults-function
+    /// [2]: https://docs.microsoft.com/sql/odbc/reference/develop-app/multiple-results
+    pub fn more_results(
+        self,
+    ) -> ReturnOption<
+        ResultSet<'con, 'param, 'col, A, CT>,
+        Statement<'con, 'param, 'col, NoCursor, A, CT>,
+    > {
+        self.next_result()
+    }
+
+    /// Return information about result set column
+    ///
+    /// See [SQLDescribeCol Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqldescribecol-function
+    pub fn describe_col<T>(
+        &mut self,
+        column_number: SQLUSMALLINT,
+        column_name: &mut T,
+        column_name_indicator: &mut SQLSMALLINT,
+        nullable: &mut Nullable,
+    ) -> Return<Option<DataType>>
+    where
+        T: OutputBuffer + ?Sized,
+    {
+        let mut data_type = SQL_UNKNOWN_TYPE;
+        let mut column_size = 0;
+        let mut decimal_digits = 0;
+        self.handle.describe_col(
+            column_number,
+            column_name,
+            column_name_indicator,
+            &mut data_type,
+            &mut column_size,
+            &mut decimal_digits,
+            nullable
+        ).map(|()| DataType::new(data_type, column_size, decimal_digits))
+    }
+}
+
+impl<'con, 'param, 'col> Statement<'con, 'param, 'col> {
+    /// Allocates a new `Statement`
+    pub fn with_parent<AC: AutocommitMode>(parent: &'con Connection<AC>) -> Return<Self> {
+        HStmt::allocate(parent.as_hdbc()).map(|handle| {
+            Statement {
+                handle,
+                parameters: PhantomData,
+                cursor: PhantomData,
+                columns: PhantomData,
+                access_plan: PhantomData,
+                cursor_type: PhantomData,
+            }
+        })
+    }
+}
+
+impl<'con, 'param, 'col, CT> Statement<'con, 'param, 'col, NoCursor, Unprepared, CT> {
+    /// Executes a preparable statement, using the current values of the
+    /// parametr marker variables.
+    ///
+    /// Parameters bound with `bind_input_parameter_at_exec` require `exec_direct_at_exec`. This
+    /// method cancels the execution and returns `Error` if the driver asks for their data, which
+    /// is indicated by `rejected_data_at_exec`.
+    ///
+    /// * See [SQLExecDirect][1]
+    /// * See [Direct Execution][2]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlexecdirect-function
+    /// [2]: https://docs.microsoft.com/sql/odbc/reference/develop-app/direct-execution-odbc
+    pub fn exec_direct<T>(
+        mut self,
+        statement_text: &T,
+    ) -> ReturnOption<
+        ResultSet<'con, 'param, 'col, Unprepared, CT>,
+        Statement<'con, 'param, 'col, NoCursor, Unprepared, CT>,
+    >
+    where
+        T: SqlStr + ?Sized,
+    {
+        match self.handle.exec_direct(statement_text) {
+            ReturnOption::Success(()) => ReturnOption::Success(self.transit()),
+            ReturnOption::Info(()) => ReturnOption::Info(self.transit()),
+            ReturnOption::NoData(()) => ReturnOption::NoData(self.transit()),
+            ReturnOption::Error(()) => ReturnOption::Error(self.transit()),
+        }
+    }
+}
+
+impl<'con, 'param, 'col, CT> Statement<'con, 'param, 'col, NoCursor, Prepared, CT> {
+    /// Return information about result set column
+    ///
+    /// See [SQLDescribeCol Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqldescribecol-function
+    pub fn describe_col<T>(
+        &mut self,
+        column_number: SQLUSMALLINT,
+        column_name: &mut T,
+        column_name_indicator: &mut SQLSMALLINT,
+        nullable: &mut Nullable,
+    ) -> Return<Option<DataType>>
+    where
+        T: OutputBuffer + ?Sized,
+    {
+        let mut data_type = SQL_UNKNOWN_TYPE;
+        let mut column_size = 0;
+        let mut decimal_digits = 0;
+        self.handle.describe_col(
+            column_number,
+            column_name,
+            column_name_indicator,
+            &mut data_type,
+            &mut column_size,
+            &mut decimal_digits,
+            nullable
+        ).map(|()| DataType::new(data_type, column_size, decimal_digits))
+    }
+
+    /// Returns the number of parameter markers in the prepared statement
+    ///
+    /// See [SQLNumParams Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlnumparams-function
+    pub fn num_params(&self) -> Return<SQLSMALLINT> {
+        self.handle.num_params()
+    }
+
+    /// Return information about the parameter marker `parameter_number`, starting at `1`. `None`
+    /// if the type is unknown or not covered by `DataType`.
+    ///
+    /// See [SQLDescribeParam Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqldescribeparam-function
+    pub fn describe_param(
+        &mut self,
+        parameter_number: SQLUSMALLINT,
+        nullable: &mut Nullable,
+    ) -> Return<Option<DataType>> {
+        let mut data_type = 0;
+        let mut parameter_size = 0;
+        let mut decimal_digits = 0;
+        let mut raw_nullable = 0;
+        self.handle.describe_param(
+            parameter_number,
+            &mut data_type,
+            &mut parameter_size,
+            &mut decimal_digits,
+            &mut raw_nullable,
+        ).map(|()| {
+            *nullable = match raw_nullable {
+                0 => SQL_NO_NULLS,
+                1 => SQL_NULLABLE,
+                _ => SQL_NULLABLE_UNKNOWN,
+            };
+            DataType::from_raw(data_type, parameter_size, decimal_digits)
+        })
+    }
+
+    /// Executes a prepared statement, using the current values fo the
+    /// parameter marker variables
+    /// if any parameter markers exist in the statement.
+    ///
+    /// Parameters bound with `bind_input_parameter_at_exec` require `execute_at_exec`. This
+    /// method cancels the execution and returns `Error` if the driver asks for their data, which
+    /// is indicated by `rejected_data_at_exec`.
+    ///
+    /// See [SQLExecute Function][1]
+    /// See [Prepared Execution][2]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlexecute-function
+    /// [2]: https://docs.microsoft.com/sql/odbc/reference/develop-app/prepared-execution-odbc
+    pub fn execute(mut self) -> ReturnOption<ResultSet<'con, 'param, 'col, Prepared, CT>, Self> {
+        match self.handle.execute() {
+            ReturnOption::Success(()) => ReturnOption::Success(self.transit()),
+            ReturnOption::Info(()) => ReturnOption::Info(self.transit()),
+            ReturnOption::Error(()) => ReturnOption::Error(self.transit()),
+            ReturnOption::NoData(()) => ReturnOption::NoData(self.transit()),
+        }
+    }
+}
+
+impl<'con, 'param, 'col, A, CT> Statement<'con, 'param, 'col, NoCursor, A, CT> {
+    /// Prepares a `Statement` for execution by creating an Access Plan.
+    ///
+    /// Statements which are already prepared may be prepared again, replacing the previous Access
+    /// Plan. If preparing fails the statement is no longer prepared, so it is returned as
+    /// `Unprepared` in either case.
+    ///
+    /// See [SQLPrepare Function][1]
+    /// See [Prepare and Execute a Statement (ODBC)][2]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlprepare-function
+    /// [2]: https://docs.microsoft.com/sql/relational-databases/native-client-odbc-how-to/execute-queries/prepare-and-execute-a-statement-odbc
+    pub fn prepare<T>(
+        mut self,
+        statement_text: &T,
+    ) -> Return<
+        Statement<'con, 'param, 'col, NoCursor, Prepared, CT>,
+        Statement<'con, 'param, 'col, NoCursor, Unprepared, CT>,
+    >
+    where
+        T: SqlStr + ?Sized,
+    {
+        match self.handle.prepare(statement_text) {
+            Success(()) => Success(self.transit()),
+            Info(()) => Info(self.transit()),
+            Error(()) => Error(self.transit()),
+        }
+    }
+
+    /// Same as `more_results` of a statement with a cursor. Allows moving on after a statement
+    /// of a batch, which did not return a result set.
+    pub fn more_results(
+        self,
+    ) -> ReturnOption<
+        ResultSet<'con, 'param, 'col, A, CT>,
+        Statement<'con, 'param, 'col, NoCursor, A, CT>,
+    > {
+        self.next_result()
+    }
+
+    /// Limits the time to wait for the statement to execute. Fractions of a second are rounded up.
+    /// A timeout of zero waits indefinitely, which is the default. Executions exceeding the
+    /// timeout fail with a diagnostic record of kind `ErrorKind::Timeout`. Drivers may substitute
+    /// a different value, in which case `Info` is returned.
+    ///
+    /// See [Statement Attributes][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlsetstmtattr-function
+    pub fn set_query_timeout(mut self, timeout: Duration) -> Return<Self, Self> {
+        match self.handle.set_query_timeout(timeout::seconds(timeout)) {
+            Success(()) => Success(self),
+            Info(()) => Info(self),
+            Error(()) => Error(self),
+        }
+    }
+}
+
+impl<'con, 'param, 'col, A, CT> Statement<'con, 'param, 'col, Positioned, A, CT> {
+    /// Retrieves data for a single column or output parameter.
+    ///
+    /// See [SQLGetData][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlgetdata-function
+    pub fn get_data<T>(
+        &mut self,
+        col_or_param_num: SQLUSMALLINT,
+        target: &mut T,
+    ) -> ReturnOption<Indicator>
+    where
+        T: CDataType + ?Sized,
+    {
+        self.handle.get_data(col_or_param_num, target)
+    }
+
+    /// Number of rows fetched by the last call to `fetch` into the row set configured with
+    /// `bind_row_set`. Always `1` if no row set has been configured.
+    pub fn rows_fetched(&self) -> Return<SQLULEN> {
+        // The buffer lives at least as long as 'col
+        unsafe { self.handle.rows_fetched().map(|rows_fetched| rows_fetched.unwrap_or(1)) }
+    }
+
+    /// Status of a row within the row set configured with `bind_row_set`. `row_index` starts at
+    /// `0`. Always `RowStatus::Success` if no row set has been configured.
+    ///
+    /// # Panics
+    /// If `row_index` is not smaller than the number of rows in the row set.
+    pub fn row_status(&self, row_index: usize) -> Return<RowStatus> {
+        let row_array_size = match self.handle.row_array_size() {
+            Success(row_array_size) | Info(row_array_size) => row_array_size as usize,
+            Error(()) => return Error(()),
+        };
+        assert!(row_index < row_array_size, "Row index out of bounds.");
+        // The buffer lives at least as long as 'col and holds `row_array_size` elements
+        unsafe {
+            self.handle
+                .row_status(row_index)
+                .map(|status| status.map_or(RowStatus::Success, RowStatus::from))
+        }
+    }
+}
+
+/// Returns a pointer to the first indicator. Panics if there are fewer than `len` indicators or if
+/// an indicator claims more than `element_length` bytes.
+fn checked_indicators(
+    indicators: Option<&[SQLLEN]>,
+    len: usize,
+    element_length: SQLLEN,
+) -> *const SQLLEN {
+    match indicators {
+        Some(indicators) => {
+            assert!(
+                indicators.len() >= len,
+                "Indicators must hold an element for each set of parameters."
+            );
+            assert!(
+                indicators[..len].iter().all(|&ind| ind <= element_length),
+                "Indicator cannot be larger than buffer length."
+            );
+            indicators.as_ptr()
+        }
+        None => null(),
+    }
+}
+
+/// Panics if `field` does not lie within the memory of `row`.
+fn assert_within_row<R, T: ?Sized>(row: *const R, field: &T) {
+    let row_start = row as usize;
+    let field_ptr: *const T = field;
+    let field_start = field_ptr as *const u8 as usize;
+    assert!(
+        field_start >= row_start &&
+            field_start + size_of_val(field) <= row_start + size_of::<R>(),
+        "Bound field must lie within the row."
+    );
+}
+
+impl<'con, 'param, 'col, C, A, CT> Diagnostics for Statement<'con, 'param, 'col, C, A, CT> {
+    fn diagnostics(
+        &self,
+        rec_number: SQLSMALLINT,
+        message_text: &mut [SQLCHAR],
+    ) -> ReturnOption<DiagResult> {
+        self.handle.diagnostics(rec_number, message_text)
+    }
+}
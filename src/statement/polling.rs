@@ -0,0 +1,196 @@
+//! Executing statements asynchronously, by polling the driver until the function is complete.
+//!
+//! See [Asynchronous Execution (Polling Method)][1]
+//! [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/asynchronous-execution-polling-method
+use super::*;
+use std::thread::sleep;
+
+impl<'con, 'param, 'col, A, CT> Statement<'con, 'param, 'col, NoCursor, A, CT> {
+    /// Enables or disables asynchronous execution. If enabled, `exec_direct_async`,
+    /// `execute_async`, `fetch_async` and `more_results_async` return `StillExecuting` instead of
+    /// blocking the calling thread. All other functions, including the blocking variants of these,
+    /// still wait for completion.
+    ///
+    /// See [Statement Attributes][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlsetstmtattr-function
+    pub fn set_async_enable(mut self, enabled: bool) -> Return<Self, Self> {
+        match self.handle.set_async_enable(enabled) {
+            Success(()) => Success(self),
+            Info(()) => Info(self),
+            Error(()) => Error(self),
+        }
+    }
+
+    /// Same as `more_results`, but returns `StillExecuting` rather than blocking, if asynchronous
+    /// execution is enabled.
+    ///
+    /// # Safety
+    /// A statement returned as `StillExecuting` must not be leaked. See `Executing`.
+    #[allow(clippy::type_complexity)]
+    pub unsafe fn more_results_async(
+        mut self,
+    ) -> ReturnAsync<
+        ResultSet<'con, 'param, 'col, A, CT>,
+        Statement<'con, 'param, 'col, Executing<Open>, A, CT>,
+        Statement<'con, 'param, 'col, NoCursor, A, CT>,
+    > {
+        let ret = self.handle.more_results_async();
+        self.transit_async(ret)
+    }
+}
+
+impl<'con, 'param, 'col, CT> Statement<'con, 'param, 'col, NoCursor, Unprepared, CT> {
+    /// Same as `exec_direct`, but returns `StillExecuting` rather than blocking, if asynchronous
+    /// execution is enabled. Use `poll` on the executing statement to drive it to completion.
+    ///
+    /// # Safety
+    /// A statement returned as `StillExecuting` must not be leaked, since the driver still reads
+    /// the bound parameters and writes to the bound columns. See `Executing`.
+    #[allow(clippy::type_complexity)]
+    pub unsafe fn exec_direct_async<T>(
+        mut self,
+        statement_text: &T,
+    ) -> ReturnAsync<
+        ResultSet<'con, 'param, 'col, Unprepared, CT>,
+        Statement<'con, 'param, 'col, Executing<Open>, Unprepared, CT>,
+        Self,
+    >
+    where
+        T: SqlStr + ?Sized,
+    {
+        let ret = self.handle.exec_direct_async(statement_text);
+        self.transit_async(ret)
+    }
+}
+
+impl<'con, 'param, 'col, CT> Statement<'con, 'param, 'col, NoCursor, Prepared, CT> {
+    /// Same as `execute`, but returns `StillExecuting` rather than blocking, if asynchronous
+    /// execution is enabled. Use `poll` on the executing statement to drive it to completion.
+    ///
+    /// # Safety
+    /// A statement returned as `StillExecuting` must not be leaked, since the driver still reads
+    /// the bound parameters and writes to the bound columns. See `Executing`.
+    #[allow(clippy::type_complexity)]
+    pub unsafe fn execute_async(
+        mut self,
+    ) -> ReturnAsync<
+        ResultSet<'con, 'param, 'col, Prepared, CT>,
+        Statement<'con, 'param, 'col, Executing<Open>, Prepared, CT>,
+        Self,
+    > {
+        let ret = self.handle.execute_async();
+        self.transit_async(ret)
+    }
+}
+
+impl<'con, 'param, 'col, C, A, CT> Statement<'con, 'param, 'col, C, A, CT>
+where
+    C: CursorState,
+{
+    /// Same as `fetch`, but returns `StillExecuting` rather than blocking, if asynchronous
+    /// execution is enabled. Use `poll` on the executing statement to drive it to completion.
+    ///
+    /// # Safety
+    /// A statement returned as `StillExecuting` must not be leaked, since the driver still writes
+    /// to the buffers bound to the columns. See `Executing`.
+    #[allow(clippy::type_complexity)]
+    pub unsafe fn fetch_async(
+        mut self,
+    ) -> ReturnAsync<
+        Statement<'con, 'param, 'col, Positioned, A, CT>,
+        Statement<'con, 'param, 'col, Executing<Positioned>, A, CT>,
+        Statement<'con, 'param, 'col, NoCursor, A, CT>,
+    > {
+        let ret = self.handle.fetch_async();
+        self.transit_async(ret)
+    }
+
+    /// Same as `more_results`, but returns `StillExecuting` rather than blocking, if asynchronous
+    /// execution is enabled.
+    ///
+    /// # Safety
+    /// A statement returned as `StillExecuting` must not be leaked. See `Executing`.
+    #[allow(clippy::type_complexity)]
+    pub unsafe fn more_results_async(
+        mut self,
+    ) -> ReturnAsync<
+        ResultSet<'con, 'param, 'col, A, CT>,
+        Statement<'con, 'param, 'col, Executing<Open>, A, CT>,
+        Statement<'con, 'param, 'col, NoCursor, A, CT>,
+    > {
+        let ret = self.handle.more_results_async();
+        self.transit_async(ret)
+    }
+}
+
+impl<'con, 'param, 'col, S, A, CT> Statement<'con, 'param, 'col, Executing<S>, A, CT> {
+    /// Calls the function, which is still executing asynchronously, again. Returns
+    /// `StillExecuting` until it is complete. Each call returns immediately, so a single thread is
+    /// able to drive many statements.
+    #[allow(clippy::type_complexity)]
+    pub fn poll(
+        mut self,
+    ) -> ReturnAsync<
+        Statement<'con, 'param, 'col, S, A, CT>,
+        Self,
+        Statement<'con, 'param, 'col, NoCursor, A, CT>,
+    > {
+        let ret = self.handle.poll();
+        self.transit_async(ret)
+    }
+
+    /// Requests the asynchronously executing function to be canceled and waits for it to
+    /// complete. Canceled functions return `Error` with a diagnostic record of kind
+    /// `ErrorKind::Canceled`. The function may still complete normally, if cancellation came too
+    /// late.
+    ///
+    /// See [Canceling Asynchronous Processing][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/canceling-asynchronous-processing
+    #[allow(clippy::type_complexity)]
+    pub fn cancel(
+        mut self,
+    ) -> ReturnOption<
+        Statement<'con, 'param, 'col, S, A, CT>,
+        Statement<'con, 'param, 'col, NoCursor, A, CT>,
+    > {
+        // Even if the request fails, polling drives the function to completion
+        let _ = self.handle.cancel();
+        let backoff = Backoff::default();
+        let mut delay = backoff.initial;
+        let mut statement = self;
+        loop {
+            match statement.poll() {
+                ReturnAsync::Success(s) => return ReturnOption::Success(s),
+                ReturnAsync::Info(s) => return ReturnOption::Info(s),
+                ReturnAsync::StillExecuting(s) => {
+                    statement = s;
+                    sleep(delay);
+                    delay = backoff.next(delay);
+                }
+                ReturnAsync::NoData(s) => return ReturnOption::NoData(s),
+                ReturnAsync::Error(s) => return ReturnOption::Error(s),
+            }
+        }
+    }
+}
+
+impl<'con, 'param, 'col, S, A, CT> Statement<'con, 'param, 'col, S, A, CT> {
+    /// Express the state transition of a function executed asynchronously
+    #[allow(clippy::type_complexity)]
+    fn transit_async<S2, P>(
+        self,
+        ret: ReturnAsync<()>,
+    ) -> ReturnAsync<
+        Statement<'con, 'param, 'col, S2, A, CT>,
+        Statement<'con, 'param, 'col, P, A, CT>,
+        Statement<'con, 'param, 'col, NoCursor, A, CT>,
+    > {
+        match ret {
+            ReturnAsync::Success(()) => ReturnAsync::Success(self.transit()),
+            ReturnAsync::Info(()) => ReturnAsync::Info(self.transit()),
+            ReturnAsync::StillExecuting(()) => ReturnAsync::StillExecuting(self.transit()),
+            ReturnAsync::NoData(()) => ReturnAsync::NoData(self.transit()),
+            ReturnAsync::Error(()) => ReturnAsync::Error(self.transit()),
+        }
+    }
+}
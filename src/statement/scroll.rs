@@ -0,0 +1,122 @@
+//! Configuring and moving scrollable cursors.
+//!
+//! See [Scrollable Cursors][1]
+//! [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/scrollable-cursors
+use super::*;
+use ffi::{SQL_ATTR_CURSOR_SCROLLABLE, SQL_ATTR_CURSOR_SENSITIVITY, SQL_ATTR_CURSOR_TYPE,
+          SQL_CURSOR_FORWARD_ONLY, SQL_NONSCROLLABLE, SQL_SCROLLABLE};
+
+impl<'con, 'param, 'col, CT> Statement<'con, 'param, 'col, NoCursor, Unprepared, CT> {
+    /// Requests scrollable cursors for the result sets created by this statement, leaving the
+    /// choice of the cursor type to the driver. Returns `Error` if the driver substitutes a
+    /// forward only cursor.
+    ///
+    /// See [Statement Attributes][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlsetstmtattr-function
+    pub fn set_scrollable(
+        mut self,
+    ) -> Return<Statement<'con, 'param, 'col, NoCursor, Unprepared, Scrollable>, Self> {
+        let value = SQL_SCROLLABLE as SQLPOINTER;
+        match unsafe { self.handle.set_attribute(SQL_ATTR_CURSOR_SCROLLABLE, value) } {
+            Success(()) => Success(self.transit_cursor_type()),
+            Info(()) if self.is_scrollable() => Info(self.transit_cursor_type()),
+            Info(()) | Error(()) => Error(self),
+        }
+    }
+
+    /// Requests scrollable cursors of a specific type for the result sets created by this
+    /// statement. Drivers may substitute a different type, in which case `Info` is returned. Should
+    /// the driver substitute a forward only cursor, `Error` is returned instead.
+    pub fn set_cursor_type(
+        mut self,
+        cursor_type: CursorType,
+    ) -> Return<Statement<'con, 'param, 'col, NoCursor, Unprepared, Scrollable>, Self> {
+        let value = cursor_type.value() as SQLPOINTER;
+        match unsafe { self.handle.set_attribute(SQL_ATTR_CURSOR_TYPE, value) } {
+            Success(()) => Success(self.transit_cursor_type()),
+            Info(()) if self.is_scrollable() => Info(self.transit_cursor_type()),
+            Info(()) | Error(()) => Error(self),
+        }
+    }
+
+    /// Reads back the cursor attributes, after the driver has substituted a value. A cursor is
+    /// scrollable, if either its type is not forward only or it is declared scrollable.
+    fn is_scrollable(&self) -> bool {
+        let cursor_type = self.handle.attribute(SQL_ATTR_CURSOR_TYPE).success::<()>();
+        let scrollable = self.handle.attribute(SQL_ATTR_CURSOR_SCROLLABLE).success::<()>();
+        match (cursor_type, scrollable) {
+            (Ok(cursor_type), Ok(scrollable)) => {
+                cursor_type != SQL_CURSOR_FORWARD_ONLY || scrollable == SQL_SCROLLABLE
+            }
+            _ => false,
+        }
+    }
+
+    /// Restores forward only cursors for the result sets created by this statement.
+    pub fn set_forward_only(
+        mut self,
+    ) -> Return<Statement<'con, 'param, 'col, NoCursor, Unprepared, ForwardOnly>, Self> {
+        let attributes = [
+            (SQL_ATTR_CURSOR_TYPE, SQL_CURSOR_FORWARD_ONLY as SQLPOINTER),
+            (SQL_ATTR_CURSOR_SCROLLABLE, SQL_NONSCROLLABLE as SQLPOINTER),
+        ];
+        let mut info = false;
+        for &(attribute, value) in &attributes {
+            match unsafe { self.handle.set_attribute(attribute, value) } {
+                Success(()) => (),
+                Info(()) => info = true,
+                Error(()) => return Error(self),
+            }
+        }
+        if info {
+            Info(self.transit_cursor_type())
+        } else {
+            Success(self.transit_cursor_type())
+        }
+    }
+
+    /// Specifies whether cursors created by this statement reflect changes made to the result set
+    /// by other cursors. Depending on the driver this may change the cursor type, yet never turns
+    /// a scrollable cursor into a forward only one.
+    pub fn set_cursor_sensitivity(
+        mut self,
+        cursor_sensitivity: CursorSensitivity,
+    ) -> Return<Self, Self> {
+        let value = cursor_sensitivity.value() as SQLPOINTER;
+        match unsafe { self.handle.set_attribute(SQL_ATTR_CURSOR_SENSITIVITY, value) } {
+            Success(()) => Success(self),
+            Info(()) => Info(self),
+            Error(()) => Error(self),
+        }
+    }
+}
+
+impl<'con, 'param, 'col, C, A> Statement<'con, 'param, 'col, C, A, Scrollable>
+where
+    C: CursorState,
+{
+    /// Moves the cursor to a row set determined by `fetch_orientation` and `fetch_offset`.
+    ///
+    /// `fetch_offset` is the row number for `SQL_FETCH_ABSOLUTE` and the number of rows to move
+    /// for `SQL_FETCH_RELATIVE`. It is ignored otherwise. Returns `NoData` if the cursor is moved
+    /// before the start or beyond the end of the result set. The cursor stays open, so it is still
+    /// possible to scroll back.
+    ///
+    /// See [SQLFetchScroll Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlfetchscroll-function
+    pub fn fetch_scroll(
+        mut self,
+        fetch_orientation: FetchOrientation,
+        fetch_offset: SQLLEN,
+    ) -> ReturnOption<
+        Statement<'con, 'param, 'col, Positioned, A, Scrollable>,
+        Statement<'con, 'param, 'col, Open, A, Scrollable>,
+    > {
+        match self.handle.fetch_scroll(fetch_orientation, fetch_offset) {
+            ReturnOption::Success(()) => ReturnOption::Success(self.transit()),
+            ReturnOption::Info(()) => ReturnOption::Info(self.transit()),
+            ReturnOption::NoData(()) => ReturnOption::NoData(self.transit()),
+            ReturnOption::Error(()) => ReturnOption::Error(self.transit()),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! Updating, deleting and inserting rows using the buffers bound to the result set.
+//!
+//! See [Updating Data with SQLSetPos][1]
+//! See [Updating Data with SQLBulkOperations][2]
+//! [1]: https://docs.microsoft.com/sql/odbc/reference/develop-app/updating-data-with-sqlsetpos
+//! [2]: https://docs.microsoft.com/sql/odbc/reference/develop-app/updating-data-with-sqlbulkoperations
+use super::*;
+use ffi::{SQL_ATTR_CONCURRENCY, SQL_ATTR_USE_BOOKMARKS, SQL_UB_OFF, SQL_UB_VARIABLE};
+
+impl<'con, 'param, 'col, CT> Statement<'con, 'param, 'col, NoCursor, Unprepared, CT> {
+    /// Sets the concurrency control used by cursors created by this statement. Updating rows
+    /// requires a concurrency other than `Concurrency::ReadOnly`. Drivers may substitute a
+    /// different concurrency, in which case `Info` is returned.
+    pub fn set_concurrency(mut self, concurrency: Concurrency) -> Return<Self, Self> {
+        let value = concurrency.value() as SQLPOINTER;
+        match unsafe { self.handle.set_attribute(SQL_ATTR_CONCURRENCY, value) } {
+            Success(()) => Success(self),
+            Info(()) => Info(self),
+            Error(()) => Error(self),
+        }
+    }
+
+    /// Enables or disables variable length bookmarks. Bookmarks are required by the
+    /// `UpdateByBookmark`, `DeleteByBookmark` and `FetchByBookmark` bulk operations and are bound
+    /// to column `0`.
+    pub fn set_use_bookmarks(mut self, use_bookmarks: bool) -> Return<Self, Self> {
+        let value = if use_bookmarks {
+            SQL_UB_VARIABLE
+        } else {
+            SQL_UB_OFF
+        };
+        match unsafe { self.handle.set_attribute(SQL_ATTR_USE_BOOKMARKS, value as SQLPOINTER) } {
+            Success(()) => Success(self),
+            Info(()) => Info(self),
+            Error(()) => Error(self),
+        }
+    }
+}
+
+impl<'con, 'param, 'col, A, CT> Statement<'con, 'param, 'col, Positioned, A, CT> {
+    /// Performs `operation` on a row of the current row set, using the buffers bound with
+    /// `bind_col` or the `bind_*_array` functions.
+    ///
+    /// # Arguments
+    /// * `row_number` - Position of the row within the row set, starting at `1`. `0` performs the
+    ///   operation on every row in the row set.
+    /// * `operation` - Position, refresh, update or delete the row
+    /// * `lock_type` - Lock to place on the row after performing the operation
+    ///
+    /// See [SQLSetPos Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlsetpos-function
+    pub fn set_pos(
+        &mut self,
+        row_number: SQLULEN,
+        operation: SetPosOperation,
+        lock_type: LockType,
+    ) -> Return<()> {
+        // Bound buffers live at least as long as 'col
+        unsafe { self.handle.set_pos(row_number, operation.value(), lock_type.value()) }
+    }
+}
+
+impl<'con, 'param, 'col, C, A, CT> Statement<'con, 'param, 'col, C, A, CT>
+where
+    C: CursorState,
+{
+    /// Inserts the rows held by the bound buffers (`BulkOperation::Add`), or updates, deletes or
+    /// fetches the rows identified by the bookmarks bound to column `0`. The number of rows is the size of the
+    /// row set configured with `bind_row_set`.
+    ///
+    /// See [SQLBulkOperations Function][1]
+    /// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlbulkoperations-function
+    pub fn bulk_operations(&mut self, operation: BulkOperation) -> Return<()> {
+        // Bound buffers live at least as long as 'col
+        unsafe { self.handle.bulk_operations(operation.value()) }
+    }
+}
@@ -0,0 +1,30 @@
+use super::*;
+use ffi::SQL_API_ODBC3_ALL_FUNCTIONS_SIZE;
+use sys::*;
+
+
+/// Set of the ODBC functions supported by the driver of a connection, as returned by
+/// `Connection::supported_functions`.
+///
+/// Allows choosing between alternative code paths at runtime, e.g. falling back if a driver does
+/// not support `SQLMoreResults`, instead of failing with `IM001` (Driver does not support this
+/// function).
+///
+/// See [SQLGetFunctions Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlgetfunctions-function
+#[derive(Debug, Clone)]
+pub struct SupportedFunctions {
+    bits: [SQLUSMALLINT; SQL_API_ODBC3_ALL_FUNCTIONS_SIZE],
+}
+
+impl SupportedFunctions {
+    pub(crate) fn new(bits: [SQLUSMALLINT; SQL_API_ODBC3_ALL_FUNCTIONS_SIZE]) -> Self {
+        SupportedFunctions { bits }
+    }
+
+    /// `true` if the driver supports the function
+    pub fn supports(&self, function: FunctionId) -> bool {
+        let id = function.value() as usize;
+        self.bits[id >> 4] & (1 << (id & 0xF)) != 0
+    }
+}
@@ -0,0 +1,14 @@
+use sys::*;
+use std::time::Duration;
+
+/// Converts a timeout into the number of seconds expected by the ODBC timeout attributes.
+/// Fractions of a second are rounded up, so a short, non-zero timeout does not turn into `0`,
+/// which would disable the timeout altogether.
+pub(crate) fn seconds(timeout: Duration) -> SQLUINTEGER {
+    let seconds = timeout.as_secs() + if timeout.subsec_nanos() > 0 { 1 } else { 0 };
+    if seconds > SQLUINTEGER::MAX as u64 {
+        SQLUINTEGER::MAX
+    } else {
+        seconds as SQLUINTEGER
+    }
+}
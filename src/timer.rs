@@ -0,0 +1,73 @@
+//! Wakes up futures polling the driver after a delay, independent of any async runtime.
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError, Sender};
+use std::sync::OnceLock;
+use std::thread;
+use std::task::Waker;
+use std::time::{Duration, Instant};
+
+/// Wakes `waker` once `delay` has passed. All wake ups are handled by a single background thread,
+/// which is started the first time this function is called.
+pub(crate) fn wake_after(delay: Duration, waker: Waker) {
+    static TIMER: OnceLock<Sender<Entry>> = OnceLock::new();
+    let timer = TIMER.get_or_init(|| {
+        let (sender, receiver) = channel();
+        thread::Builder::new()
+            .name("odbc-safe-timer".to_owned())
+            .spawn(move || run(receiver))
+            .expect("Failed to spawn timer thread");
+        sender
+    });
+    let entry = Entry {
+        deadline: Instant::now() + delay,
+        waker,
+    };
+    timer.send(entry).expect("Timer thread terminated");
+}
+
+fn run(receiver: Receiver<Entry>) {
+    let mut entries = BinaryHeap::new();
+    loop {
+        let now = Instant::now();
+        while entries.peek().is_some_and(|entry: &Entry| entry.deadline <= now) {
+            entries.pop().unwrap().waker.wake();
+        }
+        let received = match entries.peek() {
+            Some(next) => receiver.recv_timeout(next.deadline - now),
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match received {
+            Ok(entry) => entries.push(entry),
+            Err(RecvTimeoutError::Timeout) => (),
+            Err(RecvTimeoutError::Disconnected) => return,
+        }
+    }
+}
+
+/// Ordered by deadline, earliest first
+struct Entry {
+    deadline: Instant,
+    waker: Waker,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Entry) -> bool {
+        self.deadline == other.deadline
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Entry) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Entry) -> Ordering {
+        // `BinaryHeap` is a max heap
+        other.deadline.cmp(&self.deadline)
+    }
+}
@@ -0,0 +1,160 @@
+use super::*;
+use sys::*;
+use std::io::Read;
+
+/// A single row of the result set returned by `Statement::type_info`, describing a data type
+/// supported by the data source.
+///
+/// See [SQLGetTypeInfo Function][1]
+/// [1]: https://docs.microsoft.com/sql/odbc/reference/syntax/sqlgettypeinfo-function
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeInfo {
+    /// Data source dependent name of the type, e.g. `CHAR` or `varchar`. Used in `CREATE TABLE`
+    /// and `ALTER TABLE` statements.
+    pub type_name: String,
+    /// SQL data type. Either an ODBC SQL data type or a driver specific one.
+    pub data_type: SQLSMALLINT,
+    /// Maximum column size of the type. `None` if not applicable.
+    pub column_size: Option<SQLINTEGER>,
+    /// Characters used to prefix a literal, e.g. `'` for character types.
+    pub literal_prefix: Option<String>,
+    /// Characters used to terminate a literal, e.g. `'` for character types.
+    pub literal_suffix: Option<String>,
+    /// Comma separated list of the parameters used to create a column of the type, e.g.
+    /// `precision,scale` for `DECIMAL`.
+    pub create_params: Option<String>,
+    /// Whether columns of the type accept NULL values.
+    pub nullable: Nullable,
+    /// Whether character values of the type are case sensitive in collations and comparisons.
+    pub case_sensitive: bool,
+    /// How the type can be used in `WHERE` clauses.
+    pub searchable: Searchable,
+    /// Whether the type is unsigned. `None` if not applicable.
+    pub unsigned: Option<bool>,
+    /// Whether the type is an exact numeric type with a fixed precision and scale, e.g. money.
+    pub fixed_prec_scale: bool,
+    /// Whether the type is auto incrementing. `None` if not applicable.
+    pub auto_unique_value: Option<bool>,
+    /// Localized name of the type.
+    pub local_type_name: Option<String>,
+    /// Minimum scale of the type. `None` if not applicable.
+    pub minimum_scale: Option<SQLSMALLINT>,
+    /// Maximum scale of the type. `None` if not applicable.
+    pub maximum_scale: Option<SQLSMALLINT>,
+}
+
+/// Predicates a type can be used with in a `WHERE` clause.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Searchable {
+    /// The type can not be used in a `WHERE` clause.
+    None,
+    /// Only usable with `LIKE`.
+    Char,
+    /// Usable with all comparison operators, except `LIKE`.
+    Basic,
+    /// Usable with any comparison operator.
+    Searchable,
+}
+
+impl Searchable {
+    /// Decodes the `SEARCHABLE` column of `Statement::type_info` or the `Searchable` column
+    /// attribute.
+    pub(crate) fn from_raw(raw: SQLLEN) -> Searchable {
+        match raw {
+            1 => Searchable::Char,
+            2 => Searchable::Basic,
+            3 => Searchable::Searchable,
+            _ => Searchable::None,
+        }
+    }
+}
+
+impl TypeInfo {
+    /// Reads the current row of a result set returned by `Statement::type_info`. Columns must
+    /// not have been read using `get_data` yet. Returns `Error` if `TYPE_NAME` or `DATA_TYPE`,
+    /// which may not be NULL, are NULL.
+    pub fn read<'con, 'param, 'col, A, CT>(
+        row: &mut Statement<'con, 'param, 'col, Positioned, A, CT>,
+    ) -> Return<TypeInfo> {
+        let mut info = false;
+        match read_row(row, &mut info) {
+            Ok(type_info) if info => Info(type_info),
+            Ok(type_info) => Success(type_info),
+            Err(()) => Error(()),
+        }
+    }
+
+    /// The type as `DataType`, using the maximum column size and scale. `None` if the type is not
+    /// covered by `DataType`.
+    pub fn sql_data_type(&self) -> Option<DataType> {
+        let column_size = self.column_size.unwrap_or(0) as SQLULEN;
+        let scale = self.maximum_scale.unwrap_or(0);
+        DataType::from_raw(self.data_type, column_size, scale)
+    }
+}
+
+fn read_row<'con, 'param, 'col, A, CT>(
+    row: &mut Statement<'con, 'param, 'col, Positioned, A, CT>,
+    info: &mut bool,
+) -> Result<TypeInfo, ()> {
+    Ok(TypeInfo {
+        type_name: read_string(row, 1)?.ok_or(())?,
+        data_type: read_value(row, 2, info)?.ok_or(())?,
+        column_size: read_value(row, 3, info)?,
+        literal_prefix: read_string(row, 4)?,
+        literal_suffix: read_string(row, 5)?,
+        create_params: read_string(row, 6)?,
+        nullable: match read_value::<_, _, SQLSMALLINT>(row, 7, info)? {
+            Some(0) => SQL_NO_NULLS,
+            Some(1) => SQL_NULLABLE,
+            _ => SQL_NULLABLE_UNKNOWN,
+        },
+        case_sensitive: read_value::<_, _, SQLSMALLINT>(row, 8, info)? == Some(1),
+        searchable: Searchable::from_raw(
+            read_value::<_, _, SQLSMALLINT>(row, 9, info)?.unwrap_or(0) as SQLLEN,
+        ),
+        unsigned: read_value::<_, _, SQLSMALLINT>(row, 10, info)?.map(|v| v == 1),
+        fixed_prec_scale: read_value::<_, _, SQLSMALLINT>(row, 11, info)? == Some(1),
+        auto_unique_value: read_value::<_, _, SQLSMALLINT>(row, 12, info)?.map(|v| v == 1),
+        local_type_name: read_string(row, 13)?,
+        minimum_scale: read_value(row, 14, info)?,
+        maximum_scale: read_value(row, 15, info)?,
+    })
+}
+
+/// Reads a fixed size value. `None` if it is NULL.
+fn read_value<A, CT, T>(
+    row: &mut Statement<Positioned, A, CT>,
+    col: SQLUSMALLINT,
+    info: &mut bool,
+) -> Result<Option<T>, ()>
+where
+    T: CDataType + Default,
+{
+    let mut value = T::default();
+    match row.get_data(col, &mut value) {
+        ReturnOption::Success(Indicator::Null) |
+        ReturnOption::Info(Indicator::Null) |
+        ReturnOption::NoData(()) => Ok(None),
+        ReturnOption::Success(_) => Ok(Some(value)),
+        ReturnOption::Info(_) => {
+            *info = true;
+            Ok(Some(value))
+        }
+        ReturnOption::Error(()) => Err(()),
+    }
+}
+
+/// Reads a character value of arbitrary length. `None` if it is NULL.
+fn read_string<A, CT>(
+    row: &mut Statement<Positioned, A, CT>,
+    col: SQLUSMALLINT,
+) -> Result<Option<String>, ()> {
+    let mut reader = row.get_data_reader(col, SQL_C_CHAR);
+    let mut buffer = Vec::new();
+    reader.read_to_end(&mut buffer).map_err(|_| ())?;
+    match reader.indicator() {
+        Some(Indicator::Null) => Ok(None),
+        _ => Ok(Some(String::from_utf8_lossy(&buffer).into_owned())),
+    }
+}
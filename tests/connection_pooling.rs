@@ -0,0 +1,42 @@
+//! Connection pooling is a setting of the whole process. It is tested in its own binary, so it
+//! does not affect the tests in `lib.rs`.
+extern crate odbc_safe;
+extern crate odbc_sys;
+
+use odbc_safe::*;
+use odbc_sys::*;
+use std::ptr::null_mut;
+
+/// Reads `SQL_ATTR_CONNECTION_POOLING` of `env`, or of the process if `env` is null
+fn connection_pooling(env: SQLHENV) -> SQLUINTEGER {
+    let mut value: SQLUINTEGER = 0;
+    let ret = unsafe {
+        SQLGetEnvAttr(
+            env,
+            SQL_ATTR_CONNECTION_POOLING,
+            &mut value as *mut SQLUINTEGER as SQLPOINTER,
+            0,
+            null_mut(),
+        )
+    };
+    assert_eq!(SQL_SUCCESS, ret);
+    value
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn connection_pooling_per_process() {
+    Environment::set_connection_pooling(ConnectionPooling::OnePerDriver).unwrap();
+    assert_eq!(1, connection_pooling(null_mut()));
+
+    let mut env = Environment::new().unwrap();
+    env.set_driver_aware_pooling(false).unwrap();
+    assert_eq!(0, connection_pooling(env.as_raw()));
+    env.set_cp_match(CpMatch::Relaxed).unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    for _ in 0..2 {
+        let dbc = DataSource::with_parent(&env).unwrap();
+        let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+        dbc.disconnect().unwrap();
+    }
+}
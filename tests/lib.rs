@@ -130,6 +130,136 @@ fn query_result() {
     dbc.disconnect().unwrap();
 }
 
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn data_reader() {
+    use std::io::Read;
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = match stmt.exec_direct("SELECT title, NULL FROM Movies WHERE year=1968;") {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            ReturnOption::NoData(_) => panic!("No Data"),
+            ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        let mut stmt = match stmt.fetch() {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            ReturnOption::Error(s) => panic!("Error during fetching row: {}", get_last_error(&s)),
+            ReturnOption::NoData(_) => panic!("Empty result set returned from SELECT"),
+        };
+        let mut title = Vec::new();
+        {
+            // Read in small chunks, so the value is truncated several times
+            let mut reader = stmt.get_data_reader(1, odbc_sys::SQL_C_CHAR);
+            let mut chunk = [0u8; 4];
+            loop {
+                let n = reader.read(&mut chunk).unwrap();
+                if n == 0 {
+                    break;
+                }
+                title.extend_from_slice(&chunk[..n]);
+            }
+            assert!(!reader.has_warning());
+        }
+        assert_eq!("2001: A Space Odyssey".as_bytes(), &title[..]);
+        let mut null = Vec::new();
+        let mut reader = stmt.get_data_reader(2, odbc_sys::SQL_C_CHAR);
+        reader.read_to_end(&mut null).unwrap();
+        assert!(null.is_empty());
+        assert!(matches!(reader.indicator(), Some(Indicator::Null)));
+    }
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn multiple_result_sets() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = match stmt.exec_direct("SELECT title FROM Movies; SELECT title, year FROM Movies")
+        {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            ReturnOption::NoData(_) => panic!("No Data"),
+            ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        assert_eq!(1, stmt.num_result_cols().unwrap());
+        let stmt = match stmt.more_results() {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            ReturnOption::NoData(_) => panic!("Second result set missing"),
+            ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        assert_eq!(2, stmt.num_result_cols().unwrap());
+        match stmt.more_results() {
+            ReturnOption::NoData(_) => (),
+            ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+            _ => panic!("Only two result sets expected"),
+        };
+    }
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn scrollable_cursor() {
+    use odbc_sys::{SQL_FETCH_ABSOLUTE, SQL_FETCH_LAST, SQL_FETCH_PRIOR};
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = stmt.set_cursor_type(CursorType::Static).unwrap();
+        let stmt = match stmt.exec_direct("SELECT year FROM Movies ORDER BY year") {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            ReturnOption::NoData(_) => panic!("No Data"),
+            ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        let mut year = 0i32;
+        let mut stmt = match stmt.fetch_scroll(SQL_FETCH_LAST, 0) {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            ReturnOption::NoData(_) => panic!("No Data"),
+            ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        assert!(matches!(stmt.get_data(1, &mut year), ReturnOption::Success(_)));
+        assert_eq!(1993, year);
+        let mut stmt = match stmt.fetch_scroll(SQL_FETCH_PRIOR, 0) {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            ReturnOption::NoData(_) => panic!("No Data"),
+            ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        assert!(matches!(stmt.get_data(1, &mut year), ReturnOption::Success(_)));
+        assert_eq!(1968, year);
+        let stmt = match stmt.fetch_scroll(SQL_FETCH_PRIOR, 0) {
+            ReturnOption::NoData(s) => s,
+            ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+            _ => panic!("Moved before start of result set"),
+        };
+        let mut stmt = match stmt.fetch_scroll(SQL_FETCH_ABSOLUTE, 2) {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            ReturnOption::NoData(_) => panic!("No Data"),
+            ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        assert!(matches!(stmt.get_data(1, &mut year), ReturnOption::Success(_)));
+        assert_eq!(1993, year);
+    }
+    dbc.disconnect().unwrap();
+}
+
 #[cfg_attr(not(feature = "travis"), ignore)]
 #[test]
 fn describe_result() {
@@ -139,25 +269,897 @@ fn describe_result() {
     let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
     {
         let stmt = Statement::with_parent(&dbc).unwrap();
-        let mut stmt = match stmt.exec_direct("SELECT title, year FROM Movies") {
+        let mut stmt = match stmt.exec_direct("SELECT title, year FROM Movies") {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            _ => panic!("Did not return Result Set"),
+        };
+        let mut buffer = [0u8; 6];
+        let mut indicator = 0;
+        let mut nullable = odbc_sys::SQL_NO_NULLS;
+        let data_type = stmt.describe_col(1, &mut buffer[..], &mut indicator, &mut nullable)
+            .unwrap();
+        println!("DataType {:?}", data_type);
+        assert_eq!(data_type, Some(DataType::Varchar(255)));
+        assert_eq!(&buffer, b"title\0");
+        assert_eq!(indicator, 5);
+        assert_eq!(nullable, odbc_sys::SQL_NULLABLE);
+    }
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn block_cursor() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    let mut years = [0; 4];
+    let mut rows_fetched = 0;
+    let mut row_status = [0; 4];
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = stmt.bind_row_set(BindType::Column, &mut rows_fetched, &mut row_status)
+            .unwrap();
+        let stmt = stmt.bind_col_array(1, &mut years, None).unwrap();
+        let stmt = match stmt.exec_direct("SELECT year FROM Movies ORDER BY year") {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            _ => panic!("Did not return Result Set"),
+        };
+        let stmt = match stmt.fetch() {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            _ => panic!("Did not fetch row set"),
+        };
+        assert_eq!(2, stmt.rows_fetched().unwrap());
+        assert_eq!(RowStatus::Success, stmt.row_status(1).unwrap());
+        assert_eq!(RowStatus::NoRow, stmt.row_status(2).unwrap());
+    }
+    assert_eq!([1968, 1993], years[..2]);
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn set_pos() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    let mut years = [0; 2];
+    let mut rows_fetched = 0;
+    let mut row_status = [0; 2];
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = stmt.set_cursor_type(CursorType::Static).unwrap();
+        let stmt = stmt.bind_row_set(BindType::Column, &mut rows_fetched, &mut row_status)
+            .unwrap();
+        let stmt = stmt.bind_col_array(1, &mut years, None).unwrap();
+        let stmt = match stmt.exec_direct("SELECT year FROM Movies ORDER BY year") {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            _ => panic!("Did not return Result Set"),
+        };
+        let mut stmt = match stmt.fetch() {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            _ => panic!("Did not fetch row set"),
+        };
+        match stmt.set_pos(2, SetPosOperation::Position, LockType::NoChange) {
+            Success(()) => (),
+            Info(()) => assert_no_diagnostic(&stmt),
+            Error(()) => panic!("{}", get_last_error(&stmt)),
+        }
+        let mut year = 0i32;
+        assert!(matches!(stmt.get_data(1, &mut year), ReturnOption::Success(_)));
+        assert_eq!(1993, year);
+    }
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn set_pos_modifies_rows() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    let stmt = Statement::with_parent(&dbc).unwrap();
+    let create = "CREATE TEMPORARY TABLE SetPosYears (year int PRIMARY KEY); \
+                  INSERT INTO SetPosYears VALUES (1968), (1993);";
+    match stmt.exec_direct(create) {
+        ReturnOption::Success(_) |
+        ReturnOption::Info(_) |
+        ReturnOption::NoData(_) => (),
+        ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+    };
+    let mut years = [0; 2];
+    let mut rows_fetched = 0;
+    let mut row_status = [0; 2];
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = stmt.set_concurrency(Concurrency::Lock).unwrap();
+        let stmt = stmt.set_cursor_type(CursorType::KeysetDriven).unwrap();
+        let stmt = stmt.bind_row_set(BindType::Column, &mut rows_fetched, &mut row_status)
+            .unwrap();
+        let stmt = stmt.bind_col_array(1, &mut years, None).unwrap();
+        let stmt = match stmt.exec_direct("SELECT year FROM SetPosYears ORDER BY year") {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            _ => panic!("Did not return Result Set"),
+        };
+        let mut stmt = match stmt.fetch() {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            _ => panic!("Did not fetch row set"),
+        };
+        for &operation in &[SetPosOperation::Refresh, SetPosOperation::Update] {
+            match stmt.set_pos(0, operation, LockType::NoChange) {
+                Success(()) | Info(()) => (),
+                Error(()) => panic!("{}", get_last_error(&stmt)),
+            }
+        }
+        match stmt.set_pos(1, SetPosOperation::Delete, LockType::NoChange) {
+            Success(()) | Info(()) => (),
+            Error(()) => panic!("{}", get_last_error(&stmt)),
+        }
+    }
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = match stmt.exec_direct("SELECT year FROM SetPosYears") {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            _ => panic!("Did not return Result Set"),
+        };
+        let mut stmt = match stmt.fetch() {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            _ => panic!("Deleted all rows"),
+        };
+        let mut year = 0i32;
+        assert!(matches!(stmt.get_data(1, &mut year), ReturnOption::Success(_)));
+        assert_eq!(1993, year);
+    }
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn bulk_add() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    let stmt = Statement::with_parent(&dbc).unwrap();
+    match stmt.exec_direct("CREATE TEMPORARY TABLE BulkYears (year int PRIMARY KEY)") {
+        ReturnOption::Success(_) |
+        ReturnOption::Info(_) |
+        ReturnOption::NoData(_) => (),
+        ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+    };
+    let mut years = [2001, 2002];
+    let mut rows_fetched = 0;
+    let mut row_status = [0; 2];
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = stmt.set_concurrency(Concurrency::Lock).unwrap();
+        let stmt = stmt.set_cursor_type(CursorType::KeysetDriven).unwrap();
+        let stmt = stmt.bind_row_set(BindType::Column, &mut rows_fetched, &mut row_status)
+            .unwrap();
+        let stmt = stmt.bind_col_array(1, &mut years, None).unwrap();
+        let mut stmt = match stmt.exec_direct("SELECT year FROM BulkYears") {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            _ => panic!("Did not return Result Set"),
+        };
+        match stmt.bulk_operations(BulkOperation::Add) {
+            Success(()) | Info(()) => (),
+            Error(()) => panic!("{}", get_last_error(&stmt)),
+        }
+    }
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = match stmt.exec_direct("SELECT COUNT(*) FROM BulkYears") {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            _ => panic!("Did not return Result Set"),
+        };
+        let mut stmt = match stmt.fetch() {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            _ => panic!("Did not fetch row"),
+        };
+        let mut count = 0i64;
+        assert!(matches!(stmt.get_data(1, &mut count), ReturnOption::Success(_)));
+        assert_eq!(2, count);
+    }
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn parameter_array() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    let years = [2001, 2002, 2003];
+    let mut params_processed = 0;
+    let mut param_status = [0; 3];
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        match stmt.exec_direct("CREATE TEMPORARY TABLE Years (year int)") {
+            ReturnOption::Success(_) |
+            ReturnOption::Info(_) |
+            ReturnOption::NoData(_) => (),
+            ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = stmt.bind_param_set(&mut params_processed, &mut param_status).unwrap();
+        let stmt = stmt.bind_input_parameter_array(1, DataType::Integer, &years, None)
+            .unwrap();
+        match stmt.exec_direct("INSERT INTO Years (year) VALUES (?)") {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => {
+                assert_eq!(3, s.params_processed().unwrap());
+                assert_eq!(ParamStatus::Success, s.param_status(2).unwrap());
+            }
+            ReturnOption::NoData(_) => panic!("No Data"),
+            ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+        };
+    }
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn data_at_execution() {
+    use std::io::Write;
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    let mut indicator = 0;
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        match stmt.exec_direct("CREATE TEMPORARY TABLE Texts (text varchar(255))") {
+            ReturnOption::Success(_) |
+            ReturnOption::Info(_) |
+            ReturnOption::NoData(_) => (),
+            ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = stmt.bind_input_parameter_at_exec(
+            1,
+            DataType::Varchar(255),
+            odbc_sys::SQL_C_CHAR,
+            Some(11),
+            &mut indicator,
+        ).unwrap();
+        let stmt = match stmt.exec_direct("INSERT INTO Texts (text) VALUES (?)") {
+            ReturnOption::Error(s) => s,
+            _ => panic!("Expected data-at-execution parameter to be rejected"),
+        };
+        assert!(stmt.rejected_data_at_exec());
+        let stmt = match stmt.exec_direct_at_exec("INSERT INTO Texts (text) VALUES (?)") {
+            ReturnNeedData::NeedData(s) => s,
+            ReturnNeedData::Error(s) => panic!("{}", get_last_error(&s)),
+            _ => panic!("Expected need data"),
+        };
+        let mut writer = match stmt.param_data() {
+            ReturnNeedData::NeedData(w) => w,
+            ReturnNeedData::Error(s) => panic!("{}", get_last_error(&s)),
+            _ => panic!("Expected need data"),
+        };
+        assert_eq!(1, writer.parameter_number());
+        writer.write_all(b"Hello").unwrap();
+        writer.write_all(b", Data").unwrap();
+        match writer.param_data() {
+            ReturnNeedData::Success(s) => assert_eq!(1, s.affected_row_count().unwrap()),
+            ReturnNeedData::Info(s) => assert_no_diagnostic(&s),
+            ReturnNeedData::NeedData(_) => panic!("No more data needed"),
+            ReturnNeedData::NoData(_) => panic!("No Data"),
+            ReturnNeedData::Error(s) => panic!("{}", get_last_error(&s)),
+        };
+    }
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn output_parameters() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    let mut value = 20;
+    let mut value_indicator = 4;
+    let mut doubled = 0;
+    let mut doubled_indicator = 0;
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        match stmt.exec_direct(
+            "CREATE FUNCTION pg_temp.increment_and_double(INOUT value int, OUT doubled int) \
+             AS 'SELECT value + 1, value * 2' LANGUAGE SQL",
+        ) {
+            ReturnOption::Success(_) |
+            ReturnOption::Info(_) |
+            ReturnOption::NoData(_) => (),
+            ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = stmt.bind_input_output_parameter(
+            1,
+            DataType::Integer,
+            &mut value,
+            &mut value_indicator,
+        ).unwrap();
+        let stmt = stmt.bind_output_parameter(
+            2,
+            DataType::Integer,
+            &mut doubled,
+            &mut doubled_indicator,
+        ).unwrap();
+        // Output parameters are available once all result sets have been processed
+        let stmt = match stmt.exec_direct("{call pg_temp.increment_and_double(?, ?)}") {
+            ReturnOption::Success(s) | ReturnOption::Info(s) => {
+                match s.more_results() {
+                    ReturnOption::NoData(s) => s,
+                    ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+                    _ => panic!("Unexpected result set"),
+                }
+            }
+            ReturnOption::NoData(s) => s,
+            ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        stmt.reset_parameters();
+    }
+    assert_eq!(21, value);
+    assert_eq!(40, doubled);
+    assert_eq!(4, doubled_indicator);
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn catalog_columns() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = match stmt.tables(&b""[..], "", "movies", "TABLE") {
+            Success(s) | Info(s) => s,
+            Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        assert_eq!(5, stmt.num_result_cols().unwrap());
+        let mut stmt = match stmt.fetch() {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            _ => panic!("Table Movies not found"),
+        };
+        let mut buffer = [0u8; 256];
+        match stmt.get_data(3, &mut buffer as &mut [u8]) {
+            ReturnOption::Success(Indicator::Length(i)) => {
+                assert_eq!("movies".as_bytes(), &buffer[..(i as usize)])
+            }
+            _ => panic!("No table name returned"),
+        }
+
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = match stmt.columns("", "", "movies", "") {
+            Success(s) | Info(s) => s,
+            Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        assert_eq!(18, stmt.num_result_cols().unwrap());
+        // Movies has two columns
+        let stmt = match stmt.fetch() {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            _ => panic!("Column title not found"),
+        };
+        let stmt = match stmt.fetch() {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            _ => panic!("Column year not found"),
+        };
+        match stmt.fetch() {
+            ReturnOption::NoData(_) => (),
+            _ => panic!("Movies has only two columns"),
+        };
+    }
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn catalog_keys() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = match stmt.primary_keys("", "", "movies") {
+            Success(s) | Info(s) => s,
+            Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        assert_eq!(6, stmt.num_result_cols().unwrap());
+
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = match stmt.foreign_keys("", "", "movies", "", "", "") {
+            Success(s) | Info(s) => s,
+            Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        assert_eq!(14, stmt.num_result_cols().unwrap());
+
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = match stmt.statistics("", "", "movies", IndexType::All, Accuracy::Quick) {
+            Success(s) | Info(s) => s,
+            Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        assert_eq!(13, stmt.num_result_cols().unwrap());
+
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = match stmt.special_columns(
+            IdentifierType::BestRowId,
+            "",
+            "",
+            "movies",
+            RowIdScope::Transaction,
+            NullableColumns::Include,
+        ) {
+            Success(s) | Info(s) => s,
+            Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        assert_eq!(8, stmt.num_result_cols().unwrap());
+    }
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn procedure_columns() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = match stmt.procedures("", "", "%") {
+            Success(s) | Info(s) => s,
+            Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        assert_eq!(8, stmt.num_result_cols().unwrap());
+
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = match stmt.procedure_columns("", "pg_catalog", "abs", "%") {
+            Success(s) | Info(s) => s,
+            Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        assert_eq!(19, stmt.num_result_cols().unwrap());
+        let mut stmt = match stmt.fetch() {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            _ => panic!("abs has no columns"),
+        };
+        let mut column_type: i16 = 0;
+        assert!(matches!(stmt.get_data(5, &mut column_type), ReturnOption::Success(_)));
+        assert_ne!(ProcedureColumnType::ResultColumn, column_type.into());
+    }
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn type_info() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = match stmt.type_info(Some(DataType::Integer)) {
+            Success(s) | Info(s) => s,
+            Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        assert_eq!(19, stmt.num_result_cols().unwrap());
+        let mut stmt = match stmt.fetch() {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            _ => panic!("No integer type"),
+        };
+        let type_info = match TypeInfo::read(&mut stmt) {
+            Success(t) | Info(t) => t,
+            Error(()) => panic!("{}", get_last_error(&stmt)),
+        };
+        assert_eq!("int4", type_info.type_name);
+        assert_eq!(Some(DataType::Integer), type_info.sql_data_type());
+        assert_eq!(Searchable::Searchable, type_info.searchable);
+    }
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = match stmt.type_info(Some(DataType::Date)) {
+            Success(s) | Info(s) => s,
+            Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        let mut stmt = match stmt.fetch() {
+            ReturnOption::Success(s) |
+            ReturnOption::Info(s) => s,
+            _ => panic!("No date type"),
+        };
+        let type_info = match TypeInfo::read(&mut stmt) {
+            Success(t) | Info(t) => t,
+            Error(()) => panic!("{}", get_last_error(&stmt)),
+        };
+        assert_eq!(Some(DataType::Date), type_info.sql_data_type());
+    }
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn col_attribute() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let mut stmt = match stmt.exec_direct("SELECT title AS name, year FROM Movies") {
             ReturnOption::Success(s) |
             ReturnOption::Info(s) => s,
             _ => panic!("Did not return Result Set"),
         };
-        let mut buffer = [0u8; 6];
+        assert_eq!(
+            2,
+            stmt.numeric_col_attribute(1, NumericColAttribute::Count).unwrap()
+        );
+        assert_eq!(
+            255,
+            stmt.numeric_col_attribute(1, NumericColAttribute::Length).unwrap()
+        );
+        let mut buffer = [0u8; 32];
         let mut indicator = 0;
-        let mut nullable = odbc_sys::SQL_NO_NULLS;
-        let data_type = stmt.describe_col(1, &mut buffer[..], &mut indicator, &mut nullable)
+        stmt.string_col_attribute(1, StringColAttribute::Label, &mut buffer[..], &mut indicator)
             .unwrap();
-        println!("DataType {:?}", data_type);
-        assert_eq!(data_type, Some(DataType::Varchar(255)));
-        assert_eq!(&buffer, b"title\0");
-        assert_eq!(indicator, 5);
-        assert_eq!(nullable, odbc_sys::SQL_NULLABLE);
+        assert_eq!("name".as_bytes(), &buffer[..(indicator as usize)]);
+        stmt.string_col_attribute(
+            1,
+            StringColAttribute::BaseColumnName,
+            &mut buffer[..],
+            &mut indicator,
+        ).unwrap();
+        assert_eq!("title".as_bytes(), &buffer[..(indicator as usize)]);
+        assert_eq!(Some(DataType::Integer), stmt.col_data_type(2).unwrap());
+        assert_eq!(odbc_sys::SQL_NULLABLE, stmt.col_nullable(2).unwrap());
+        assert_eq!(Searchable::Searchable, stmt.col_searchable(1).unwrap());
+    }
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn describe_parameters() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let mut stmt = stmt.prepare("SELECT title FROM Movies WHERE year = ? AND title = ?")
+            .unwrap();
+        assert_eq!(2, stmt.num_params().unwrap());
+        let mut nullable = odbc_sys::SQL_NO_NULLS;
+        let data_type = match stmt.describe_param(1, &mut nullable) {
+            Success(data_type) | Info(data_type) => data_type,
+            Error(()) => panic!("{}", get_last_error(&stmt)),
+        };
+        assert_eq!(Some(DataType::Integer), data_type);
+        assert_eq!(odbc_sys::SQL_NULLABLE, nullable);
+    }
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn cancel_from_other_thread() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    {
+        let mut stmt = Statement::with_parent(&dbc).unwrap();
+        let cancel_handle = stmt.cancel_handle();
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                std::thread::sleep(std::time::Duration::from_millis(500));
+                assert!(matches!(cancel_handle.cancel(), Success(true)));
+            });
+            match stmt.exec_direct("SELECT pg_sleep(10)") {
+                ReturnOption::Error(stmt) => {
+                    let mut buffer = [0u8; 512];
+                    match stmt.diagnostics(1, &mut buffer) {
+                        ReturnOption::Success(dr) | ReturnOption::Info(dr) => {
+                            assert_eq!(ErrorKind::Canceled, dr.kind())
+                        }
+                        _ => panic!("No diagnostic record"),
+                    }
+                }
+                _ => panic!("Execution has not been canceled"),
+            }
+        });
+    }
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn query_timeout() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let mut dbc = DataSource::with_parent(&env).unwrap();
+    dbc.set_login_timeout(std::time::Duration::from_secs(5)).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = stmt.set_query_timeout(std::time::Duration::from_millis(500)).unwrap();
+        match stmt.exec_direct("SELECT pg_sleep(10)") {
+            ReturnOption::Error(stmt) => {
+                let mut buffer = [0u8; 512];
+                match stmt.diagnostics(1, &mut buffer) {
+                    ReturnOption::Success(dr) | ReturnOption::Info(dr) => {
+                        assert_eq!(ErrorKind::Timeout, dr.kind())
+                    }
+                    _ => panic!("No diagnostic record"),
+                }
+            }
+            _ => panic!("Execution has not timed out"),
+        }
+    }
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn asynchronous_execution() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = stmt.set_async_enable(true).unwrap();
+        // The executing statement is never leaked
+        let stmt = match unsafe { stmt.exec_direct_async("SELECT year FROM Movies ORDER BY year") } {
+            ReturnAsync::Success(s) | ReturnAsync::Info(s) => s,
+            ReturnAsync::StillExecuting(s) => poll_to_completion(s),
+            ReturnAsync::NoData(_) => panic!("No result set"),
+            ReturnAsync::Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        let mut stmt = match unsafe { stmt.fetch_async() } {
+            ReturnAsync::Success(s) | ReturnAsync::Info(s) => s,
+            ReturnAsync::StillExecuting(s) => poll_to_completion(s),
+            ReturnAsync::NoData(_) => panic!("No Data"),
+            ReturnAsync::Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        let mut year = 0;
+        assert!(matches!(stmt.get_data(1, &mut year), ReturnOption::Success(_)));
+        assert_eq!(1968, year);
+    }
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn futures() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = match dbc.connect_future("PostgreSQL", "postgres", "") {
+        Success(future) | Info(future) => block_on(future).unwrap(),
+        Error(dbc) => panic!("{}", get_last_error(&dbc)),
+    };
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = stmt.set_async_enable(true).unwrap();
+        // The futures are never leaked
+        let future = unsafe { stmt.exec_direct_future("SELECT year FROM Movies ORDER BY year") }
+            .with_backoff(Backoff {
+                initial: std::time::Duration::from_millis(5),
+                max: std::time::Duration::from_millis(50),
+            });
+        let stmt = match block_on(future) {
+            ReturnOption::Success(s) | ReturnOption::Info(s) => s,
+            ReturnOption::NoData(_) => panic!("No result set"),
+            ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        let mut stmt = match block_on(unsafe { stmt.fetch_future() }) {
+            ReturnOption::Success(s) | ReturnOption::Info(s) => s,
+            ReturnOption::NoData(_) => panic!("No Data"),
+            ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+        };
+        let mut year = 0;
+        assert!(matches!(stmt.get_data(1, &mut year), ReturnOption::Success(_)));
+        assert_eq!(1968, year);
+    }
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn drop_future_early() {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::{Context, Waker};
+    use std::time::{Duration, Instant};
+
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = stmt.set_async_enable(true).unwrap();
+        // The future is dropped, not leaked
+        let mut future = unsafe { stmt.exec_direct_future("SELECT pg_sleep(10)") };
+        let mut cx = Context::from_waker(Waker::noop());
+        assert!(Pin::new(&mut future).poll(&mut cx).is_pending());
+        let start = Instant::now();
+        drop(future);
+        assert!(start.elapsed() < Duration::from_secs(5));
+        // The connection is still usable, after the execution has been canceled
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        match stmt.exec_direct("SELECT 1") {
+            ReturnOption::Success(_) | ReturnOption::Info(_) => (),
+            ReturnOption::NoData(_) => panic!("No result set"),
+            ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+        }
+    }
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn descriptors() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let stmt = match stmt.exec_direct("SELECT title, year FROM Movies") {
+            ReturnOption::Success(s) | ReturnOption::Info(s) => s,
+            _ => panic!("Error executing statement"),
+        };
+        let ird = stmt.imp_row_desc().unwrap();
+        assert_eq!(2, ird.field(0, DescField::Count).unwrap());
+        let mut name = [0u8; 16];
+        let len = ird.name(2, &mut name[..]).unwrap();
+        assert_eq!(b"year", &name[..len as usize]);
+    }
+    let mut year = 0i32;
+    let mut indicator = 0;
+    {
+        let desc = Descriptor::with_parent(&dbc).unwrap();
+        let desc = desc.set_rec(1, &mut year, &mut indicator).unwrap();
+        let copy = Descriptor::with_parent(&dbc).unwrap().copy_from(&desc).unwrap();
+        assert_eq!(1, copy.field(0, DescField::Count).unwrap());
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        // No buffers are bound to the statement while `copy` is set
+        let stmt = unsafe { stmt.set_app_row_desc(&copy) }.unwrap();
+        let stmt = match stmt.exec_direct("SELECT year FROM Movies ORDER BY year") {
+            ReturnOption::Success(s) | ReturnOption::Info(s) => s,
+            _ => panic!("Error executing statement"),
+        };
+        match stmt.fetch() {
+            ReturnOption::Success(_) | ReturnOption::Info(_) => (),
+            _ => panic!("No Data"),
+        }
+    }
+    assert_eq!(1968, year);
+    dbc.disconnect().unwrap();
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn reexecute_prepared_statement() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        let mut stmt = stmt.prepare("SELECT year FROM Movies ORDER BY year").unwrap();
+        for _ in 0..2 {
+            let rs = match stmt.execute() {
+                ReturnOption::Success(s) | ReturnOption::Info(s) => s,
+                _ => panic!("Error executing statement"),
+            };
+            let mut cursor = match rs.fetch() {
+                ReturnOption::Success(s) | ReturnOption::Info(s) => s,
+                _ => panic!("No Data"),
+            };
+            let mut year = 0;
+            assert!(matches!(cursor.get_data(1, &mut year), ReturnOption::Success(_)));
+            assert_eq!(1968, year);
+            stmt = cursor.close_cursor().unwrap();
+        }
+        let stmt = stmt.prepare("SELECT year FROM Movies ORDER BY year DESC").unwrap();
+        let rs = match stmt.execute() {
+            ReturnOption::Success(s) | ReturnOption::Info(s) => s,
+            _ => panic!("Error executing statement"),
+        };
+        let mut cursor = match rs.fetch() {
+            ReturnOption::Success(s) | ReturnOption::Info(s) => s,
+            _ => panic!("No Data"),
+        };
+        let mut year = 0;
+        assert!(matches!(cursor.get_data(1, &mut year), ReturnOption::Success(_)));
+        assert_eq!(1993, year);
     }
     dbc.disconnect().unwrap();
 }
 
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn transaction() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    let mut dbc = dbc.disable_autocommit().unwrap();
+    {
+        let stmt = Statement::with_parent(&dbc).unwrap();
+        match stmt.exec_direct("CREATE TEMPORARY TABLE Numbers (number int)") {
+            ReturnOption::Success(_) |
+            ReturnOption::Info(_) |
+            ReturnOption::NoData(_) => (),
+            ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+        };
+    }
+    dbc.commit().unwrap();
+    let insert = |transaction: &Transaction| {
+        let stmt = transaction.statement().unwrap();
+        match stmt.exec_direct("INSERT INTO Numbers (number) VALUES (42)") {
+            ReturnOption::Success(_) |
+            ReturnOption::Info(_) |
+            ReturnOption::NoData(_) => (),
+            ReturnOption::Error(s) => panic!("{}", get_last_error(&s)),
+        };
+    };
+    let count = |transaction: &Transaction| -> i64 {
+        let stmt = transaction.statement().unwrap();
+        let stmt = match stmt.exec_direct("SELECT COUNT(*) FROM Numbers") {
+            ReturnOption::Success(s) | ReturnOption::Info(s) => s,
+            _ => panic!("Error counting rows"),
+        };
+        let mut stmt = match stmt.fetch() {
+            ReturnOption::Success(s) | ReturnOption::Info(s) => s,
+            _ => panic!("No Data"),
+        };
+        let mut count = 0;
+        assert!(matches!(stmt.get_data(1, &mut count), ReturnOption::Success(_)));
+        count
+    };
+    {
+        // Dropped without commit
+        let transaction = dbc.transaction();
+        insert(&transaction);
+        assert_eq!(1, count(&transaction));
+    }
+    let transaction = dbc.transaction();
+    assert_eq!(0, count(&transaction));
+    insert(&transaction);
+    transaction.commit().unwrap();
+    let transaction = dbc.transaction();
+    assert_eq!(1, count(&transaction));
+    transaction.rollback().unwrap();
+}
+
 #[cfg_attr(not(feature = "travis"), ignore)]
 #[test]
 fn auto_disconnect() {
@@ -178,6 +1180,54 @@ fn not_read_only() {
     assert!(!dbc.is_read_only().unwrap());
 }
 
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn connection_info() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    assert_eq!("PostgreSQL", dbc.info(StringInfo::DbmsName).unwrap());
+    assert_eq!("\"", dbc.info(StringInfo::IdentifierQuoteChar).unwrap());
+    assert!(!dbc.info(StringInfo::Keywords).unwrap().is_empty());
+    assert!(!dbc.info(YesNoInfo::DataSourceReadOnly).unwrap());
+    assert!(dbc.info(U16Info::MaxIdentifierLen).unwrap() > 0);
+    let isolation_levels = dbc.info(TxnIsolationOption).unwrap();
+    assert!(isolation_levels.contains(IsolationLevel::ReadCommitted));
+    assert!(isolation_levels.contains(IsolationLevel::Serializable));
+    assert_eq!(TransactionSupport::All, dbc.info(TxnCapable).unwrap());
+    assert!(dbc.info(ScrollOptions).unwrap().forward_only());
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn supported_functions() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    let functions = dbc.supported_functions().unwrap();
+    assert!(functions.supports(FunctionId::ExecDirect));
+    assert!(functions.supports(FunctionId::MoreResults));
+    assert!(functions.supports(FunctionId::GetFunctions));
+}
+
+#[cfg_attr(not(feature = "travis"), ignore)]
+#[test]
+fn isolation_level() {
+    let env = Environment::new().unwrap();
+    let env: Environment<Odbc3> = env.declare_version().unwrap();
+    let dbc = DataSource::with_parent(&env).unwrap();
+    let mut dbc = dbc.connect("PostgreSQL", "postgres", "").unwrap();
+    dbc.set_isolation_level(IsolationLevel::Serializable).unwrap();
+    assert_eq!(IsolationLevel::Serializable, dbc.isolation_level().unwrap());
+    dbc.set_isolation_level(IsolationLevel::ReadCommitted).unwrap();
+    assert_eq!(IsolationLevel::ReadCommitted, dbc.isolation_level().unwrap());
+    // Raw values of levels defined by ODBC are treated like the levels themselves
+    dbc.set_isolation_level(IsolationLevel::Other(8)).unwrap();
+    assert_eq!(IsolationLevel::Serializable, dbc.isolation_level().unwrap());
+}
+
 /// Checks for a diagnstic record. Should one be present this function panics printing the contents
 /// of said record.
 fn assert_no_diagnostic(diag: &Diagnostics) {
@@ -210,3 +1260,41 @@ fn get_last_error(diag: &Diagnostics) -> String {
         ReturnOption::NoData(()) => panic!("No diagnostic available"),
     }
 }
+
+/// Polls an asynchronously executing statement until it is complete. Panics on errors.
+fn poll_to_completion<'con, 'param, 'col, S, A, CT>(
+    mut stmt: Statement<'con, 'param, 'col, Executing<S>, A, CT>,
+) -> Statement<'con, 'param, 'col, S, A, CT> {
+    loop {
+        stmt = match stmt.poll() {
+            ReturnAsync::Success(s) | ReturnAsync::Info(s) => return s,
+            ReturnAsync::StillExecuting(s) => s,
+            ReturnAsync::NoData(_) => panic!("No Data"),
+            ReturnAsync::Error(s) => panic!("{}", get_last_error(&s)),
+        }
+    }
+}
+
+/// Minimal executor, blocking the current thread until `future` is complete.
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread::{self, Thread};
+
+    struct ThreadWaker(Thread);
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let mut future = Box::pin(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}